@@ -0,0 +1,275 @@
+//! Utilities for reading files stored inside of
+//! archives.
+//!
+//! Zip and tar archives are supported, where tar
+//! archives may optionally be compressed with
+//! gzip, bzip2, or xz.  Archives stored inside of
+//! other archives are read recursively.  Members
+//! are given paths formed from the archive path,
+//! the member separator, and the member path,
+//! such as "dump.zip!/src/main.c".  Archives
+//! nested deeper than MAX_DEPTH are skipped, so
+//! an archive containing itself can't recurse
+//! forever.
+
+///////////////
+// Constants //
+///////////////
+
+/// The separator placed between the path
+/// of an archive and the path of a member
+/// stored inside of it.
+pub const MEMBER_SEPARATOR : &str = "!/";
+
+/// The amount of bytes needed from the start
+/// of a file to detect whether it may be an
+/// archive.
+pub const HEADER_LENGTH : usize = 512;
+
+/// The deepest an archive may be nested
+/// inside of other archives and still be
+/// read.  Deeper archives are skipped.
+pub const MAX_DEPTH : usize = 16;
+
+const MAGIC_ZIP      : &[u8] = b"PK\x03\x04";
+const MAGIC_TAR      : &[u8] = b"ustar";
+const OFFSET_TAR     : usize = 257;
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// What was found when reading data
+/// with read_members.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ArchiveData {
+   /// The data is an archive, and its
+   /// members were read.
+   Archive,
+
+   /// The data is not an archive.  If the
+   /// data was compressed, the decompressed
+   /// data is stored so it doesn't need to
+   /// be decompressed again.
+   Plain(Option<Vec<u8>>),
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Checks the first bytes of a file to see
+/// whether it may be an archive.  This does
+/// not guarantee the file is an archive, as
+/// compressed files must be decompressed to
+/// know whether they contain a tar archive.
+pub fn may_be_archive(
+   header : & [u8],
+) -> bool {
+   return header.starts_with(MAGIC_ZIP)
+      || crate::decompress::Compression::detect(header).is_some()
+      || internal_is_tar(header);
+}
+
+/// Reads every member of an archive, calling
+/// a closure with the member's path and data
+/// for each member which is not itself an
+/// archive.  Nested archives are read
+/// recursively up to MAX_DEPTH, and compressed
+/// members which aren't archives are passed
+/// decompressed.  No member or decompressed
+/// archive may be larger than the size limit
+/// in bytes.  Members which are too large, too
+/// deeply nested, or can't be read are skipped,
+/// and the rest of the archive is still read.
+/// If the data is not an archive,
+/// ArchiveData::Plain is returned and the
+/// closure is never called.  An error is only
+/// returned when the archive can't be opened,
+/// in which case the closure is never called
+/// either.
+pub fn read_members<F>(
+   path        : & std::path::Path,
+   data        : & [u8],
+   size_limit  : u64,
+   per_member  : & mut F,
+) -> crate::collect::Result<ArchiveData>
+where F: FnMut(std::path::PathBuf, Vec<u8>) {
+   return internal_read_members(path, data, size_limit, 0, per_member);
+}
+
+/// Creates the path of a member stored
+/// inside of an archive.
+pub fn member_path(
+   archive  : & std::path::Path,
+   member   : & std::path::Path,
+) -> std::path::PathBuf {
+   let mut path = archive.as_os_str().to_os_string();
+   path.push(MEMBER_SEPARATOR);
+   path.push(member.as_os_str());
+
+   return std::path::PathBuf::from(path);
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Checks for the "ustar" magic inside
+/// of a tar header.
+fn internal_is_tar(
+   data : & [u8],
+) -> bool {
+   return data.get(OFFSET_TAR..OFFSET_TAR + MAGIC_TAR.len()) == Some(MAGIC_TAR);
+}
+
+/// Executes the same as read_members, but
+/// the archive is nested inside of the given
+/// amount of other archives.
+fn internal_read_members<F>(
+   path        : & std::path::Path,
+   data        : & [u8],
+   size_limit  : u64,
+   depth       : usize,
+   per_member  : & mut F,
+) -> crate::collect::Result<ArchiveData>
+where F: FnMut(std::path::PathBuf, Vec<u8>) {
+   // Zip archives are read directly
+   if data.starts_with(MAGIC_ZIP) {
+      internal_read_zip(path, data, size_limit, depth, per_member)?;
+      return Ok(ArchiveData::Archive);
+   }
+
+   // Compressed data must be decompressed to
+   // check for a tar archive inside
+   let decompressed = crate::decompress::decompress_file(path, data, size_limit)?;
+   let tar = match &decompressed {
+      Some(d)  => d.as_slice(),
+      None     => data,
+   };
+
+   if internal_is_tar(tar) {
+      internal_read_tar(path, tar, size_limit, depth, per_member)?;
+      return Ok(ArchiveData::Archive);
+   }
+
+   return Ok(ArchiveData::Plain(decompressed));
+}
+
+/// Passes a member of an archive nested
+/// inside of the given amount of other
+/// archives to the closure, or recurses into
+/// it if it is an archive.  Members which only
+/// look like archives are passed as they are,
+/// and members nested too deeply to be read
+/// are skipped.
+fn internal_read_member<F>(
+   path        : std::path::PathBuf,
+   data        : Vec<u8>,
+   size_limit  : u64,
+   depth       : usize,
+   per_member  : & mut F,
+) where F: FnMut(std::path::PathBuf, Vec<u8>) {
+   if may_be_archive(&data) {
+      if depth >= MAX_DEPTH {
+         return;
+      }
+
+      match internal_read_members(&path, &data, size_limit, depth + 1, per_member) {
+         Ok(ArchiveData::Archive)                     => return,
+         Ok(ArchiveData::Plain(Some(decompressed)))   => {
+            per_member(path, decompressed);
+            return;
+         },
+         Ok(ArchiveData::Plain(None)) | Err(_)        => (),
+      }
+   }
+
+   per_member(path, data);
+   return;
+}
+
+/// Reads every file stored in a zip archive.
+fn internal_read_zip<F>(
+   path        : & std::path::Path,
+   data        : & [u8],
+   size_limit  : u64,
+   depth       : usize,
+   per_member  : & mut F,
+) -> crate::collect::Result<()>
+where F: FnMut(std::path::PathBuf, Vec<u8>) {
+   use crate::collect::CollectDateError;
+
+   let mut archive = zip::ZipArchive::new(std::io::Cursor::new(data))
+      .map_err(|_| CollectDateError::InvalidData)?;
+
+   for index in 0..archive.len() {
+      // Skip members which can't be read
+      let mut member = match archive.by_index(index) {
+         Ok(m)    => m,
+         Err(_)   => continue,
+      };
+      if member.is_dir() {
+         continue;
+      }
+
+      // Skip members with unsafe or unreadable names
+      let member_name = match member.enclosed_name() {
+         Some(n)  => n.to_path_buf(),
+         None     => continue,
+      };
+
+      let buffer = match crate::decompress::read_limited(& mut member, size_limit) {
+         Ok(b)    => b,
+         Err(_)   => continue,
+      };
+
+      internal_read_member(member_path(path, &member_name), buffer, size_limit, depth, per_member);
+   }
+
+   return Ok(());
+}
+
+/// Reads every regular file stored in
+/// a tar archive.  Reading stops at the
+/// first corrupt header, keeping the
+/// members read before it.
+fn internal_read_tar<F>(
+   path        : & std::path::Path,
+   data        : & [u8],
+   size_limit  : u64,
+   depth       : usize,
+   per_member  : & mut F,
+) -> crate::collect::Result<()>
+where F: FnMut(std::path::PathBuf, Vec<u8>) {
+   use crate::collect::CollectDateError;
+
+   let mut archive = tar::Archive::new(data);
+   let entries = archive.entries().map_err(|_| CollectDateError::InvalidData)?;
+
+   // The iterator ends after an error, so a
+   // corrupt header ends the archive
+   for member in entries {
+      let mut member = match member {
+         Ok(m)    => m,
+         Err(_)   => continue,
+      };
+      if member.header().entry_type().is_file() == false {
+         continue;
+      }
+
+      // Skip members which can't be read
+      let member_name = match member.path() {
+         Ok(n)    => n.into_owned(),
+         Err(_)   => continue,
+      };
+      let buffer = match crate::decompress::read_limited(& mut member, size_limit) {
+         Ok(b)    => b,
+         Err(_)   => continue,
+      };
+
+      internal_read_member(member_path(path, &member_name), buffer, size_limit, depth, per_member);
+   }
+
+   return Ok(());
+}
@@ -2,6 +2,14 @@
 
 use clap::Parser;
 
+///////////////
+// Constants //
+///////////////
+
+/// The input file name which refers to
+/// standard input.
+pub const STDIN_PATH : &str = "-";
+
 /////////////////////////////////
 // Struct and enum definitions //
 /////////////////////////////////
@@ -14,10 +22,19 @@ pub struct Args {
       short             = 'f',
       long              = "file",
       value_name        = "INPUT_FILE",
-      help              = "Input file or directory to be searched",
-      required          = true,
+      help              = "Input files or directories to be searched, \"-\" searches stdin as a single file",
+      num_args          = 1..,
+      required_unless_present_any = ["files_from_stdin", "diff"],
    )]
-   input_file  : String,
+   input_files : Vec<String>,
+
+   #[arg(
+      short          = 'l',
+      long           = "files-from-stdin",
+      help           = "Read a NUL- or newline-delimited list of input files or directories from stdin",
+      required       = false,
+   )]
+   files_from_stdin : bool,
 
    #[arg(
       short          = 'o',
@@ -44,13 +61,238 @@ pub struct Args {
    )]
    threads     : Option<std::num::NonZeroUsize>,
 
+   #[arg(
+      short          = 'a',
+      long           = "archives",
+      help           = "Search inside zip, tar, tar.gz, and tar.bz2 archives, including nested archives",
+      required       = false,
+   )]
+   archives    : bool,
+
+   #[arg(
+      long           = "no-decompress",
+      help           = "Do not decompress gzip, bzip2, or xz compressed files before searching them",
+      required       = false,
+   )]
+   no_decompress : bool,
+
+   #[arg(
+      long           = "size-limit",
+      value_name     = "BYTES",
+      help           = "Maximum size of a decompressed file or archive member, larger files are skipped",
+      required       = false,
+   )]
+   size_limit  : Option<u64>,
+
+   #[arg(
+      short          = 'b',
+      long           = "binary-strings",
+      help           = "Search the printable ASCII and UTF-16LE strings inside of binary files instead of skipping them",
+      required       = false,
+   )]
+   binary_strings : bool,
+
+   #[arg(
+      long           = "min-string-length",
+      value_name     = "LENGTH",
+      help           = "Minimum amount of characters in a string found inside of a binary file",
+      required       = false,
+   )]
+   min_string_length : Option<usize>,
+
    #[arg(
       short          = 'e',
       long           = "regexp",
-      help           = "A regular expression used to find dates.  Requires named capture groups $d, $m, and $y for day, month, and year respectively.",
+      help           = "A regular expression used to find dates.  Requires the named capture group $y for the year, and optionally $m and $d for the month and day, or $q for a quarter from 1 to 4.  Optional capture groups $H, $M, and $S capture the time of day, and $z captures a time zone abbreviation or UTC offset.  The optional capture group $a captures the author of the date, and $e captures an era of BC, BCE, AD, or CE.  Ranges capture the last day in $d2 and optionally its month and year in $m2 and $y2, where a missing $y2 is the next year if $m2 comes before the start month.  Lists capture extra days of the same month in $l.",
       required       = false,
    )]
    date_regex  : Option<String>,
+
+   #[arg(
+      short          = 'x',
+      long           = "extractor",
+      value_name     = "FORMAT",
+      help           = "Built-in date formats to search for: month-day-year, day-month-year, spelled-out, c-date, ctime, rfc2822, month-day-range, month-day-list, month-year, quarter, or copyright.  Every format is used unless this or a regular expression is given.",
+      num_args       = 1..,
+      required       = false,
+   )]
+   extractors  : Vec<crate::extract::BuiltinFormat>,
+
+   #[arg(
+      long                 = "fuzzy-months",
+      value_name           = "DISTANCE",
+      help                 = "Recover misspelled month names such as \"Feburary\" within the given edit distance, defaulting to 2.  Recovered dates are flagged as fuzzy.",
+      num_args             = 0..=1,
+      default_missing_value = "2",
+      required             = false,
+   )]
+   fuzzy_months : Option<usize>,
+
+   #[arg(
+      long           = "near-misses",
+      help           = "List matches which were rejected because they aren't valid dates, such as \"February 30, 1995\", along with the reason they were rejected",
+      required       = false,
+   )]
+   near_misses : bool,
+
+   #[arg(
+      long           = "calendar",
+      value_name     = "CALENDAR",
+      help           = "Calendar dates are written in: gregorian (proleptic, no year zero), julian (no year zero), or astronomical (proleptic Gregorian with year zero as 1 BCE).  Defaults to gregorian.",
+      required       = false,
+   )]
+   calendar : Option<crate::date::Calendar>,
+
+   #[arg(
+      long           = "min-confidence",
+      value_name     = "SCORE",
+      help           = "Minimum confidence score from 0.0 to 1.0 a date must have to be kept, based on the strictness of its format, the plausibility of its year, and whether it's inside a comment or near a keyword such as \"created\" or \"modified\"",
+      required       = false,
+   )]
+   min_confidence : Option<f64>,
+
+   #[arg(
+      long           = "comments-only",
+      help           = "Only search the comments of source code, choosing the comment syntax by file extension: C/C++ (// and /* */), Rust (// and nested /* */), assembly (;), shell, Python, and Makefiles (#), or Lua (--).  Files in other languages are searched in full.",
+      required       = false,
+   )]
+   comments_only : bool,
+
+   #[arg(
+      long           = "category",
+      value_name     = "CATEGORY",
+      help           = "Compare files using only their dates in a category: created, modified, copyright, or other.  Dates are classified by the closest keyword before them on their line, such as \"Created:\" or \"Last update\".  Not available with --format html or svg.",
+      required       = false,
+   )]
+   category : Option<crate::category::DateCategory>,
+
+   #[arg(
+      long           = "keyword",
+      value_name     = "CATEGORY:KEYWORD",
+      help           = "Add a keyword used to classify dates, such as \"modified:last edit\", in addition to the default keywords",
+      value_parser   = crate::category::KeywordTable::parse_entry,
+      required       = false,
+   )]
+   keywords : Vec<(crate::category::DateCategory, String)>,
+
+   #[arg(
+      long           = "author-regex",
+      value_name     = "REGEX",
+      help           = "A regular expression matched against the rest of the line after each date to find its author, captured with the named capture group $a.  Replaces the default patterns, which match \"by NAME\" and \"- NAME:\".",
+      required       = false,
+   )]
+   author_regex : Vec<String>,
+
+   #[arg(
+      long           = "changelog",
+      help           = "Parse revision history blocks, where each line starts with a date followed by an author and description, and show each file's edit history",
+      required       = false,
+   )]
+   changelog : bool,
+
+   #[arg(
+      long           = "directory-depth",
+      value_name     = "DEPTH",
+      help           = "Roll up the dates of files into per-directory statistics, down to the given depth below the common ancestor of the files",
+      required       = false,
+   )]
+   directory_depth : Option<usize>,
+
+   #[arg(
+      long           = "format",
+      value_name     = "FORMAT",
+      help           = "The format of the report: text, json, markdown, a self-contained html page, or an svg chart.  Defaults to text.",
+      required       = false,
+   )]
+   format : Option<crate::report::OutputFormat>,
+
+   #[arg(
+      long           = "chart",
+      value_name     = "CHART",
+      help           = "The chart drawn with --format svg: histogram of dates per month, or gantt of each file's date span.  Defaults to gantt.",
+      required       = false,
+   )]
+   chart : Option<crate::svg::SvgChart>,
+
+   #[arg(
+      long           = "chart-width",
+      value_name     = "PIXELS",
+      help           = "The width of svg charts in pixels.  Defaults to 800.",
+      required       = false,
+   )]
+   chart_width : Option<usize>,
+
+   #[arg(
+      long           = "chart-height",
+      value_name     = "PIXELS",
+      help           = "The height of svg charts in pixels.  Defaults to 400.",
+      required       = false,
+   )]
+   chart_height : Option<usize>,
+
+   #[arg(
+      long           = "sort",
+      value_name     = "ORDER",
+      help           = "The order to list files in: oldest, newest, path, count, span, or latest-first.  Defaults to oldest.",
+      required       = false,
+   )]
+   sort : Option<crate::sort::SortOrder>,
+
+   #[arg(
+      long           = "reverse",
+      help           = "Reverse the order files are listed in",
+      required       = false,
+   )]
+   reverse : bool,
+
+   #[arg(
+      long           = "summary-only",
+      help           = "Only write the summary of the report, leaving out the dates of every file.  Not available with --format html or svg.",
+      required       = false,
+   )]
+   summary_only : bool,
+
+   #[arg(
+      long           = "top",
+      value_name     = "COUNT",
+      help           = "Only list the given amount of oldest and newest files with dates.  Not available with --format html or svg.",
+      required       = false,
+   )]
+   top : Option<usize>,
+
+   #[arg(
+      long           = "max-dates",
+      value_name     = "COUNT",
+      help           = "Maximum amount of dates listed for each file.  Not available with --format html or svg.",
+      required       = false,
+   )]
+   max_dates : Option<usize>,
+
+   #[arg(
+      long           = "heatmap",
+      value_name     = "YEARS",
+      help           = "Draw a calendar heatmap of the dates found on each day, over a year or range of years such as 1995-1997",
+      required       = false,
+      value_parser   = crate::heatmap::Heatmap::parse_years,
+   )]
+   heatmap : Option<std::ops::RangeInclusive<isize>>,
+
+   #[arg(
+      long           = "save-scan",
+      value_name     = "SCAN_FILE",
+      help           = "Save every file's dates to a scan file which can be compared with --diff",
+      required       = false,
+   )]
+   save_scan : Option<String>,
+
+   #[arg(
+      long           = "diff",
+      value_names    = ["OLD_SCAN", "NEW_SCAN"],
+      help           = "Compare two scan files saved with --save-scan instead of searching, written as text or json",
+      num_args       = 2,
+      required       = false,
+   )]
+   diff : Vec<String>,
 }
 
 ////////////////////
@@ -66,14 +308,63 @@ impl Args {
    where I: IntoIterator<Item = T>,
          T: Into<std::ffi::OsString> + Clone,
    {
-      return Self::parse_from(argument_list);
+      use clap::CommandFactory;
+      use crate::report::OutputFormat;
+
+      let args = Self::parse_from(argument_list);
+
+      // Standard input can only be consumed once
+      if args.files_from_stdin && args.input_files.iter().any(|f| f == STDIN_PATH) {
+         Self::command().error(
+            clap::error::ErrorKind::ArgumentConflict,
+            "stdin cannot be both searched and used as a file list",
+         ).exit();
+      }
+
+      // Html pages and svg charts always show every file and date
+      let selection = [
+         ("--top",            args.top.is_some()        ),
+         ("--summary-only",   args.summary_only         ),
+         ("--max-dates",      args.max_dates.is_some()  ),
+         ("--category",       args.category.is_some()   ),
+      ];
+      if matches!(args.format(), OutputFormat::Html | OutputFormat::Svg) {
+         if let Some((flag, _)) = selection.iter().find(|(_, used)| *used) {
+            Self::command().error(
+               clap::error::ErrorKind::ArgumentConflict,
+               format!("{flag} cannot be used with --format {}", args.format()),
+            ).exit();
+         }
+      }
+
+      // Confidence scores are always between 0.0 and 1.0
+      if let Some(confidence) = args.min_confidence {
+         if !(0.0..=1.0).contains(&confidence) {
+            Self::command().error(
+               clap::error::ErrorKind::ValueValidation,
+               format!("--min-confidence must be from 0.0 to 1.0, not {confidence}"),
+            ).exit();
+         }
+      }
+
+      return args;
    }
 
-   /// Retrieves the input file string.
-   pub fn input_file<'a>(
+   /// Retrieves the input file strings.
+   /// A value of "-" refers to standard
+   /// input.
+   pub fn input_files<'a>(
       &'a self,
-   ) -> &'a str {
-      return &self.input_file;
+   ) -> &'a [String] {
+      return &self.input_files;
+   }
+
+   /// Retrieves whether to read the list
+   /// of input files from standard input.
+   pub fn files_from_stdin(
+      & self,
+   ) -> bool {
+      return self.files_from_stdin;
    }
 
    /// Retrieves the outfile file string.
@@ -98,11 +389,235 @@ impl Args {
       return self.threads.clone();
    }
 
+   /// Retrieves whether to search inside
+   /// of archives.
+   pub fn archives(
+      & self,
+   ) -> bool {
+      return self.archives;
+   }
+
+   /// Retrieves whether to decompress
+   /// compressed files.
+   pub fn decompress(
+      & self,
+   ) -> bool {
+      return !self.no_decompress;
+   }
+
+   /// Retrieves the specified decompressed
+   /// size limit.
+   pub fn size_limit(
+      & self,
+   ) -> Option<u64> {
+      return self.size_limit;
+   }
+
+   /// Retrieves whether to search strings
+   /// inside of binary files.
+   pub fn binary_strings(
+      & self,
+   ) -> bool {
+      return self.binary_strings;
+   }
+
+   /// Retrieves the specified minimum
+   /// binary string length.
+   pub fn min_string_length(
+      & self,
+   ) -> Option<usize> {
+      return self.min_string_length;
+   }
+
    /// Retrieves the input date regex.
    pub fn date_regex<'l>(
       &'l self,
    ) -> Option<&'l str> {
       return self.date_regex.as_ref().map(|s| s.as_str());
    }
-}
 
+   /// Retrieves the built-in date
+   /// formats to search for.
+   pub fn extractors<'l>(
+      &'l self,
+   ) -> &'l [crate::extract::BuiltinFormat] {
+      return &self.extractors;
+   }
+
+   /// Retrieves the maximum edit distance
+   /// for fuzzy month matching, if enabled.
+   pub fn fuzzy_months(
+      & self,
+   ) -> Option<usize> {
+      return self.fuzzy_months;
+   }
+
+   /// Retrieves whether to record matches
+   /// which aren't valid dates.
+   pub fn near_misses(
+      & self,
+   ) -> bool {
+      return self.near_misses;
+   }
+
+   /// Retrieves the calendar dates are
+   /// written in, defaulting to the
+   /// proleptic Gregorian calendar.
+   pub fn calendar(
+      & self,
+   ) -> crate::date::Calendar {
+      return self.calendar.unwrap_or_default();
+   }
+
+   /// Retrieves the minimum confidence score
+   /// for found dates, defaulting to keeping
+   /// every date.
+   pub fn min_confidence(
+      & self,
+   ) -> f64 {
+      return self.min_confidence.unwrap_or(0.0);
+   }
+
+   /// Retrieves whether to only search
+   /// the comments of source code.
+   pub fn comments_only(
+      & self,
+   ) -> bool {
+      return self.comments_only;
+   }
+
+   /// Retrieves the category files are
+   /// compared by, if any.
+   pub fn category(
+      & self,
+   ) -> Option<crate::category::DateCategory> {
+      return self.category;
+   }
+
+   /// Creates the keyword table used to
+   /// classify dates, containing the default
+   /// keywords followed by the given keywords.
+   pub fn keywords(
+      & self,
+   ) -> crate::category::KeywordTable {
+      let mut table = crate::category::KeywordTable::default();
+      for (category, keyword) in self.keywords.iter() {
+         table.insert(keyword, *category);
+      }
+
+      return table;
+   }
+
+   /// Retrieves the input author regexes.
+   pub fn author_regex<'l>(
+      &'l self,
+   ) -> &'l [String] {
+      return &self.author_regex;
+   }
+
+   /// Retrieves whether to parse revision
+   /// history blocks.
+   pub fn changelog(
+      & self,
+   ) -> bool {
+      return self.changelog;
+   }
+
+   /// Retrieves the depth of per-directory
+   /// statistics, if enabled.
+   pub fn directory_depth(
+      & self,
+   ) -> Option<usize> {
+      return self.directory_depth;
+   }
+
+   /// Retrieves the format of the report,
+   /// defaulting to plain text.
+   pub fn format(
+      & self,
+   ) -> crate::report::OutputFormat {
+      return self.format.unwrap_or_default();
+   }
+
+   /// Retrieves the range of years to draw
+   /// a calendar heatmap over, if enabled.
+   pub fn heatmap(
+      & self,
+   ) -> Option<std::ops::RangeInclusive<isize>> {
+      return self.heatmap.clone();
+   }
+
+   /// Retrieves the order to list files in,
+   /// defaulting to oldest first.
+   pub fn sort_order(
+      & self,
+   ) -> crate::sort::SortOrder {
+      return self.sort.unwrap_or_default();
+   }
+
+   /// Retrieves whether to reverse the order
+   /// files are listed in.
+   pub fn reverse(
+      & self,
+   ) -> bool {
+      return self.reverse;
+   }
+
+   /// Retrieves whether to only write the
+   /// summary of the report.
+   pub fn summary_only(
+      & self,
+   ) -> bool {
+      return self.summary_only;
+   }
+
+   /// Retrieves the amount of oldest and
+   /// newest files to list, if limited.
+   pub fn top(
+      & self,
+   ) -> Option<usize> {
+      return self.top;
+   }
+
+   /// Retrieves the maximum amount of dates
+   /// listed for each file, if limited.
+   pub fn max_dates(
+      & self,
+   ) -> Option<usize> {
+      return self.max_dates;
+   }
+
+   /// Retrieves the file to save the scan
+   /// to, if any.
+   pub fn save_scan<'a>(
+      &'a self,
+   ) -> Option<&'a str> {
+      return self.save_scan.as_deref();
+   }
+
+   /// Retrieves the older and newer scan
+   /// files to compare, if any.
+   pub fn diff<'a>(
+      &'a self,
+   ) -> Option<(&'a str, &'a str)> {
+      return match self.diff.as_slice() {
+         [before, after]   => Some((before.as_str(), after.as_str())),
+         _                 => None,
+      };
+   }
+
+   /// Builds the options for drawing svg
+   /// charts from the arguments.
+   pub fn svg_options(
+      & self,
+   ) -> crate::svg::SvgOptions {
+      let default = crate::svg::SvgOptions::default();
+      return crate::svg::SvgOptions{
+         chart    : self.chart.unwrap_or(default.chart),
+         width    : self.chart_width.unwrap_or(default.width),
+         height   : self.chart_height.unwrap_or(default.height),
+         order    : self.sort_order(),
+         reverse  : self.reverse(),
+      };
+   }
+}
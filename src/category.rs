@@ -0,0 +1,253 @@
+//! Utilities for classifying dates by the
+//! keywords written near them.
+//!
+//! File headers typically label their dates, such
+//! as "Created: June 1 1995", "Last update: ...",
+//! or "Copyright (C) 1995".  A KeywordTable maps
+//! keywords to date categories, and a date takes
+//! the category of the closest keyword on its line
+//! which starts before or at the date, within
+//! crate::confidence::KEYWORD_WINDOW bytes.
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// What a date found within a file
+/// describes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateCategory {
+   /// When the file was created.
+   Created,
+
+   /// When the file was last modified.
+   Modified,
+
+   /// A copyright notice.
+   Copyright,
+
+   /// A date without a known keyword.
+   Other,
+}
+
+/// Error type detailing a parsing error
+/// for DateCategory.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseDateCategoryError {
+   /// A category matching the input text
+   /// was not found.
+   NoMatchingCategory,
+
+   /// A keyword table entry isn't written
+   /// as "CATEGORY:KEYWORD".
+   InvalidKeywordEntry,
+}
+
+/// A table of keywords used to classify
+/// dates, matched case-insensitively.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeywordTable {
+   keywords : Vec<(String, DateCategory)>,
+}
+
+///////////////
+// Constants //
+///////////////
+
+/// The keywords in the default keyword table.
+pub const DEFAULT_KEYWORDS : [(&str, DateCategory); 14] = [
+   ("created",       DateCategory::Created),
+   ("creation",      DateCategory::Created),
+   ("written",       DateCategory::Created),
+   ("started",       DateCategory::Created),
+   ("modified",      DateCategory::Modified),
+   ("modification",  DateCategory::Modified),
+   ("update",        DateCategory::Modified),
+   ("revised",       DateCategory::Modified),
+   ("revision",      DateCategory::Modified),
+   ("changed",       DateCategory::Modified),
+   ("edited",        DateCategory::Modified),
+   ("copyright",     DateCategory::Copyright),
+   ("(c)",           DateCategory::Copyright),
+   ("\u{A9}",        DateCategory::Copyright),
+];
+
+////////////////////////////
+// Methods - DateCategory //
+////////////////////////////
+
+impl DateCategory {
+   /// A list of every category.
+   pub const ALL : [Self; 4] = [
+      Self::Created,
+      Self::Modified,
+      Self::Copyright,
+      Self::Other,
+   ];
+
+   /// Gets the name of the category as
+   /// used on the command line.
+   pub fn name(
+      & self,
+   ) -> &'static str {
+      return match self {
+         Self::Created     => "created",
+         Self::Modified    => "modified",
+         Self::Copyright   => "copyright",
+         Self::Other       => "other",
+      };
+   }
+}
+
+//////////////////////////////////////////
+// Trait implementations - DateCategory //
+//////////////////////////////////////////
+
+impl std::fmt::Display for DateCategory {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for DateCategory {
+   type Err = ParseDateCategoryError;
+
+   fn from_str(
+      string : & str,
+   ) -> Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|c| c.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseDateCategoryError::NoMatchingCategory);
+   }
+}
+
+////////////////////////////////////////////////////
+// Trait implementations - ParseDateCategoryError //
+////////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseDateCategoryError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingCategory   => "No matching date category",
+         Self::InvalidKeywordEntry  => "Keyword must be written as CATEGORY:KEYWORD",
+      });
+   }
+}
+
+impl std::error::Error for ParseDateCategoryError {
+}
+
+////////////////////////////
+// Methods - KeywordTable //
+////////////////////////////
+
+impl KeywordTable {
+   /// Creates a new empty KeywordTable.
+   pub fn new(
+   ) -> Self {
+      return Self{
+         keywords : Vec::new(),
+      };
+   }
+
+   /// Adds a keyword to the table.  Keywords
+   /// added later take precedence over earlier
+   /// keywords found at the same position.
+   pub fn insert(
+      & mut self,
+      keyword  : & str,
+      category : DateCategory,
+   ) -> & mut Self {
+      self.keywords.push((keyword.to_lowercase(), category));
+      return self;
+   }
+
+   /// Parses a keyword table entry written
+   /// as "CATEGORY:KEYWORD", such as
+   /// "modified:last edit".
+   pub fn parse_entry(
+      entry : & str,
+   ) -> Result<(DateCategory, String), ParseDateCategoryError> {
+      let (category, keyword) = entry.split_once(':')
+         .ok_or(ParseDateCategoryError::InvalidKeywordEntry)?;
+      if keyword.is_empty() {
+         return Err(ParseDateCategoryError::InvalidKeywordEntry);
+      }
+
+      return Ok((category.trim().parse()?, keyword.to_string()));
+   }
+
+   /// Gets a reference to the list of
+   /// keywords and their categories.
+   pub fn keywords<'l>(
+      &'l self,
+   ) -> &'l [(String, DateCategory)] {
+      return &self.keywords;
+   }
+
+   /// Classifies the date found at the given
+   /// byte offset of a text string by the
+   /// closest keyword on its line which starts
+   /// before or at the date.  The longest
+   /// keyword wins when several start at the
+   /// same position.  Dates without a keyword
+   /// are classified as DateCategory::Other.
+   pub fn classify(
+      & self,
+      text     : & str,
+      offset   : usize,
+   ) -> DateCategory {
+      // Find the window of text the keyword must start in
+      let line_start = text[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+      let line_end   = text[offset..].find('\n').map(|i| offset + i).unwrap_or(text.len());
+      let mut start  = offset.saturating_sub(crate::confidence::KEYWORD_WINDOW).max(line_start);
+      while text.is_char_boundary(start) == false {
+         start += 1;
+      }
+
+      // Lowercasing ASCII keeps byte positions the same
+      let window     = text[start..line_end].to_ascii_lowercase();
+      let relative   = offset - start;
+
+      let mut best : Option<(usize, usize, DateCategory)> = None;
+      for (keyword, category) in self.keywords.iter() {
+         let position = window.match_indices(keyword.as_str())
+            .map(|(i, _)| i)
+            .filter(|i| *i <= relative)
+            .last();
+
+         if let Some(position) = position {
+            if best.is_none_or(|(p, l, _)| (position, keyword.len()) >= (p, l)) {
+               best = Some((position, keyword.len(), *category));
+            }
+         }
+      }
+
+      return best.map(|(_, _, category)| category).unwrap_or(DateCategory::Other);
+   }
+}
+
+//////////////////////////////////////////
+// Trait implementations - KeywordTable //
+//////////////////////////////////////////
+
+impl std::default::Default for KeywordTable {
+   /// Creates a KeywordTable containing
+   /// every default keyword.
+   fn default(
+   ) -> Self {
+      let mut table = Self::new();
+      for (keyword, category) in DEFAULT_KEYWORDS {
+         table.insert(keyword, category);
+      }
+
+      return table;
+   }
+}
+
@@ -0,0 +1,242 @@
+//! Utilities for parsing revision history
+//! blocks into structured changelog entries.
+//!
+//! Many files carry a revision history inside of
+//! a comment, with a date, author, and description
+//! on each line:
+//!
+//! ```text
+//!  * June 23, 1995 - tk: fixed camera
+//!  *                     and lakitu
+//!  * June 30, 1995 by Nishiwaki: added castle
+//! ```
+//!
+//! A changelog block is a run of at least
+//! MIN_BLOCK_ENTRIES lines which start with a date,
+//! after any comment markers.  Lines between them
+//! which are indented past the date continue the
+//! description of the entry above, while any other
+//! line ends the block.
+
+///////////////
+// Constants //
+///////////////
+
+/// The minimum amount of entries in a
+/// changelog block.
+pub const MIN_BLOCK_ENTRIES : usize = 2;
+
+/// Characters which may come before the
+/// content of a line inside of a comment.
+const LEADER_CHARACTERS : [char; 8] = [
+   '/', '*', '#', ';', '-', '!', '%', '|',
+];
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// A single entry within a changelog block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChangelogEntry {
+   date        : crate::date::Date,
+   offset      : usize,
+   author      : Option<String>,
+   description : String,
+}
+
+//////////////////////////////
+// Methods - ChangelogEntry //
+//////////////////////////////
+
+impl ChangelogEntry {
+   /// Creates a new ChangelogEntry from its
+   /// date, the byte offset of the date, its
+   /// author, and its description.
+   pub fn new(
+      date        : crate::date::Date,
+      offset      : usize,
+      author      : Option<String>,
+      description : String,
+   ) -> Self {
+      return Self{
+         date        : date,
+         offset      : offset,
+         author      : author,
+         description : description,
+      };
+   }
+
+   /// Gets the date of the entry.
+   pub fn date(
+      & self,
+   ) -> crate::date::Date {
+      return self.date;
+   }
+
+   /// Gets the byte offset of the
+   /// entry's date.
+   pub fn offset(
+      & self,
+   ) -> usize {
+      return self.offset;
+   }
+
+   /// Gets the author of the entry,
+   /// if known.
+   pub fn author<'l>(
+      &'l self,
+   ) -> Option<&'l str> {
+      return self.author.as_deref();
+   }
+
+   /// Gets the description of the entry,
+   /// which may be empty.
+   pub fn description<'l>(
+      &'l self,
+   ) -> &'l str {
+      return &self.description;
+   }
+}
+
+////////////////////////////////////////////
+// Trait implementations - ChangelogEntry //
+////////////////////////////////////////////
+
+impl std::fmt::Display for ChangelogEntry {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      write!(stream, "{}", self.date)?;
+      if let Some(author) = &self.author {
+         write!(stream, " ({author})")?;
+      }
+      if self.description.is_empty() == false {
+         write!(stream, ": {}", self.description)?;
+      }
+
+      return Ok(());
+   }
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Finds every changelog entry within a text
+/// string, given the dates found within it.
+/// Entries are returned in the order they
+/// appear in the text.
+pub fn find_entries(
+   text  : & str,
+   dates : & [crate::collect::FoundDate],
+) -> Vec<ChangelogEntry> {
+   let line_starts = std::iter::once(0)
+      .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+      .collect::<Vec<_>>();
+   let line_of = |offset : usize| line_starts.partition_point(|s| *s <= offset) - 1;
+   let line_text = |line : usize| {
+      let end = line_starts.get(line + 1).map(|e| e - 1).unwrap_or(text.len());
+      return &text[line_starts[line]..end];
+   };
+
+   // Find every date which starts its line
+   let mut starts = dates.iter()
+      .filter_map(|date| date.offset().map(|offset| (offset, date)))
+      .collect::<Vec<_>>();
+   starts.sort_by_key(|(offset, _)| *offset);
+   starts.dedup_by_key(|(offset, _)| line_of(*offset));
+   starts.retain(|(offset, _)| {
+      let line = line_of(*offset);
+      return internal_content_column(line_text(line)) == Some(offset - line_starts[line]);
+   });
+
+   // Group the dates into blocks, gathering
+   // continuation lines into their descriptions
+   let mut entries   = Vec::new();
+   let mut block     = Vec::new();
+   for (index, (offset, date)) in starts.iter().enumerate() {
+      let line       = line_of(*offset);
+      let column     = offset - line_starts[line];
+      let line_end   = line_starts[line] + line_text(line).len();
+      let date_end   = (offset + date.length()).min(line_end);
+      let mut description = internal_description(&text[date_end..line_end], date.author());
+
+      // Continuation lines are indented past the date
+      let next_line = starts.get(index + 1).map(|(o, _)| line_of(*o)).unwrap_or(line_starts.len());
+      let mut continued = line + 1;
+      while continued < next_line {
+         match internal_content_column(line_text(continued)) {
+            Some(c) if c > column => {
+               let content = internal_trim_comment(&line_text(continued)[c..]);
+               if description.is_empty() == false {
+                  description.push(' ');
+               }
+               description.push_str(content);
+            },
+            _                     => break,
+         }
+         continued += 1;
+      }
+
+      block.push(ChangelogEntry::new(
+         date.date(), *offset, date.author().map(str::to_string), description,
+      ));
+
+      // The block ends unless the next date is on the next line
+      if continued != next_line || next_line == line_starts.len() {
+         if block.len() >= MIN_BLOCK_ENTRIES {
+            entries.append(&mut block);
+         }
+         block.clear();
+      }
+   }
+
+   return entries;
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Finds the byte column where the content of
+/// a line starts, after whitespace and comment
+/// markers.  Returns None if the line has no
+/// content.
+fn internal_content_column(
+   line  : & str,
+) -> Option<usize> {
+   let column = line.find(|c : char| {
+      c.is_whitespace() == false && LEADER_CHARACTERS.contains(&c) == false
+   })?;
+
+   return Some(column);
+}
+
+/// Removes a trailing comment end marker and
+/// whitespace from the content of a line.
+fn internal_trim_comment(
+   content : & str,
+) -> & str {
+   return content.trim_end().trim_end_matches("*/").trim_end();
+}
+
+/// Creates the description of an entry from
+/// the text after its date, removing the
+/// author and the punctuation around it.
+fn internal_description(
+   rest     : & str,
+   author   : Option<&str>,
+) -> String {
+   let mut rest = rest;
+   if let Some(author) = author {
+      if let Some(index) = rest.find(author) {
+         rest = &rest[index + author.len()..];
+      }
+   }
+
+   let punctuation = |c : char| c.is_whitespace() || matches!(c, ':' | '-' | ',' | '.');
+   return internal_trim_comment(rest.trim_start_matches(punctuation)).to_string();
+}
+
@@ -49,6 +49,12 @@ pub enum CollectDateError {
    /// crate::Date::ParseDateError::InvalidRegexCaptures
    /// for more information.
    InvalidRegexCaptures,
+
+   /// Decompressed data is larger than
+   /// the size limit.  See
+   /// crate::decompress for more
+   /// information.
+   DataTooLarge,
 }
 
 /// A type alias for a standard result
@@ -56,10 +62,35 @@ pub enum CollectDateError {
 /// error type.
 pub type Result<T> = std::result::Result<T, CollectDateError>;
 
+/// A date found within a file, along with
+/// the byte offset where it was found.
+/// Dates found within a date range or list,
+/// such as "June 23-25, 1995", also store
+/// the first and last date of the range.
+/// Dates with a misspelled month which was
+/// recovered by fuzzy matching are flagged.
+/// Each date has a confidence score from 0.0
+/// to 1.0, see crate::confidence for more
+/// information, and a category such as when
+/// the file was created, see crate::category
+/// for more information.  The author written
+/// next to the date is stored if found.
+#[derive(Clone, Debug)]
+pub struct FoundDate {
+   date        : crate::date::Date,
+   offset      : Option<usize>,
+   range       : Option<(crate::date::Date, crate::date::Date)>,
+   fuzzy       : bool,
+   confidence  : f64,
+   category    : crate::category::DateCategory,
+   author      : Option<String>,
+   length      : usize,
+}
+
 /// A list of dates sorted from oldest
 /// to newest.
 pub struct DateList {
-   list  : sorted_vec::SortedVec<crate::date::Date>,
+   list  : sorted_vec::SortedVec<FoundDate>,
 }
 
 /// An iterator over a DateList created
@@ -72,14 +103,17 @@ pub struct DateListIterator<'l> {
 /// A DateList gathered from a file on disk,
 /// storing the path to the file.
 pub struct FileDateList {
-   path  : std::path::PathBuf,
-   dates : DateList,
+   path        : std::path::PathBuf,
+   dates       : DateList,
+   rejected    : Vec<crate::extract::RejectedMatch>,
+   changelog   : Vec<crate::changelog::ChangelogEntry>,
 }
 
 /// A sorted list of many different files
 /// containing their collected dates.
 pub struct FileAggregateDateList {
-   files : sorted_vec::SortedVec<FileDateList>,
+   files    : sorted_vec::SortedVec<FileDateList>,
+   rejected : Vec<(std::path::PathBuf, Vec<crate::extract::RejectedMatch>)>,
 }
 
 /// An iterator over a FileAggregateDateList created
@@ -89,6 +123,71 @@ pub struct FileAggregateDateListIterator<'l> {
    index : usize,
 }
 
+/// Options controlling how files are searched
+/// for dates.  The default options search every
+/// file as plain text, decompressing any files
+/// compressed with a known format.
+#[derive(Clone, Debug)]
+pub struct SearchOptions {
+   /// Whether to search the files stored inside
+   /// of zip and tar archives, including archives
+   /// nested inside of other archives.  See
+   /// crate::archive for more information.
+   pub archives   : bool,
+
+   /// Whether to decompress files compressed
+   /// with gzip, bzip2, or xz before searching
+   /// them.  See crate::decompress for more
+   /// information.
+   pub decompress : bool,
+
+   /// The maximum size in bytes of decompressed
+   /// files and archive members.  Larger files
+   /// are skipped.
+   pub size_limit : u64,
+
+   /// Whether to search the printable strings
+   /// inside of binary files instead of skipping
+   /// them.  See crate::strings for more
+   /// information.
+   pub binary_strings : bool,
+
+   /// The minimum amount of characters in a
+   /// printable string found in a binary file.
+   pub min_string_length : usize,
+
+   /// Whether to record matches which were
+   /// rejected because they aren't valid dates,
+   /// such as "February 30, 1995".  See
+   /// crate::extract::RejectedMatch for more
+   /// information.
+   pub near_misses : bool,
+
+   /// The minimum confidence score a found
+   /// date must have to be kept, from 0.0 to
+   /// 1.0.  See crate::confidence for more
+   /// information.
+   pub min_confidence : f64,
+
+   /// Whether to only search the comments of
+   /// source code files, choosing the comment
+   /// syntax by file extension.  Files in an
+   /// unknown language are searched in full.
+   /// See crate::comments for more information.
+   pub comments_only : bool,
+
+   /// The keywords used to classify found
+   /// dates.  See crate::category for more
+   /// information.
+   pub keywords : crate::category::KeywordTable,
+
+   /// Whether to parse revision history blocks
+   /// inside of text files into changelog
+   /// entries.  See crate::changelog for more
+   /// information.
+   pub changelog : bool,
+}
+
 /// A pool of spawned threads purposed for
 /// finding all dates in a given string.
 /// Useful for multi-threading the collection
@@ -96,9 +195,25 @@ pub struct FileAggregateDateListIterator<'l> {
 /// for a given instance will exit when the
 /// instance goes out of scope.
 pub struct DateFinderThreadPool {
-   pipe_send_list    : Vec<std::sync::mpsc::Sender<std::path::PathBuf>>,
-   pipe_recv         : std::sync::mpsc::Receiver<Result<FileDateList>>,
+   pipe_send_list    : Vec<std::sync::mpsc::Sender<DateFinderJob>>,
+   pipe_recv         : std::sync::mpsc::Receiver<Vec<Result<FileDateList>>>,
    thread_send_next  : usize,
+   options           : SearchOptions,
+}
+
+/// A unit of work sent to a thread inside
+/// of a DateFinderThreadPool.
+enum DateFinderJob {
+   /// A file on disk to be read and searched.
+   File(std::path::PathBuf),
+
+   /// Data already in memory, labeled with
+   /// a path.
+   Buffer(std::path::PathBuf, Vec<u8>),
+
+   /// An archive on disk whose members are
+   /// each read and searched.
+   Archive(std::path::PathBuf),
 }
 
 //////////////////////////////////////////////
@@ -126,6 +241,8 @@ impl std::fmt::Display for CollectDateError {
             => "Invalid data",
          Self::InvalidRegexCaptures
             => "Regex does not contain $m, $d, or $y capture groups",
+         Self::DataTooLarge
+            => "Decompressed data exceeds the size limit",
       });
    }
 }
@@ -150,15 +267,295 @@ impl std::convert::From<std::io::Error> for CollectDateError {
    }
 }
 
+///////////////////////////////////////////
+// Trait implementations - SearchOptions //
+///////////////////////////////////////////
+
+impl std::default::Default for SearchOptions {
+   fn default(
+   ) -> Self {
+      return Self{
+         archives          : false,
+         decompress        : true,
+         size_limit        : crate::decompress::DEFAULT_SIZE_LIMIT,
+         binary_strings    : false,
+         min_string_length : crate::strings::DEFAULT_MIN_LENGTH,
+         near_misses       : false,
+         min_confidence    : 0.0,
+         comments_only     : false,
+         keywords          : crate::category::KeywordTable::default(),
+         changelog         : false,
+      };
+   }
+}
+
+/////////////////////////
+// Methods - FoundDate //
+/////////////////////////
+
+impl FoundDate {
+   /// Creates a new FoundDate from a date
+   /// and the byte offset where it was found.
+   /// An offset of None means the location
+   /// of the date is unknown.  The date has
+   /// a confidence score of 1.0 and is in the
+   /// category DateCategory::Other.
+   pub fn new(
+      date     : crate::date::Date,
+      offset   : Option<usize>,
+   ) -> Self {
+      return Self{
+         date        : date,
+         offset      : offset,
+         range       : None,
+         fuzzy       : false,
+         confidence  : 1.0,
+         category    : crate::category::DateCategory::Other,
+         author      : None,
+         length      : 0,
+      };
+   }
+
+   /// Creates a copy of the found date
+   /// with a different byte offset.
+   pub fn with_offset(
+      & self,
+      offset   : Option<usize>,
+   ) -> Self {
+      return Self{
+         offset   : offset,
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date
+   /// which records that it was found in
+   /// a date range or list spanning from
+   /// the first to the last given date.
+   pub fn with_range(
+      & self,
+      first    : crate::date::Date,
+      last     : crate::date::Date,
+   ) -> Self {
+      return Self{
+         range    : Some((first, last)),
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date
+   /// flagged as found using fuzzy month
+   /// matching or not.
+   pub fn with_fuzzy(
+      & self,
+      fuzzy    : bool,
+   ) -> Self {
+      return Self{
+         fuzzy    : fuzzy,
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date with
+   /// a different confidence score, clamped
+   /// from 0.0 to 1.0.
+   pub fn with_confidence(
+      & self,
+      confidence  : f64,
+   ) -> Self {
+      return Self{
+         confidence  : confidence.clamp(0.0, 1.0),
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date
+   /// with a different category.
+   pub fn with_category(
+      & self,
+      category : crate::category::DateCategory,
+   ) -> Self {
+      return Self{
+         category    : category,
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date
+   /// with a different author.
+   pub fn with_author(
+      & self,
+      author   : Option<String>,
+   ) -> Self {
+      return Self{
+         author   : author,
+         ..self.clone()
+      };
+   }
+
+   /// Creates a copy of the found date with
+   /// a different length in bytes of the text
+   /// it was found in.
+   pub fn with_length(
+      & self,
+      length   : usize,
+   ) -> Self {
+      return Self{
+         length   : length,
+         ..self.clone()
+      };
+   }
+
+   /// Gets the found date.
+   pub fn date(
+      & self,
+   ) -> crate::date::Date {
+      return self.date;
+   }
+
+   /// Gets the byte offset where the date
+   /// was found, if known.
+   pub fn offset(
+      & self,
+   ) -> Option<usize> {
+      return self.offset;
+   }
+
+   /// Gets the first and last date of the
+   /// date range or list the date was
+   /// found in, if any.
+   pub fn range(
+      & self,
+   ) -> Option<(crate::date::Date, crate::date::Date)> {
+      return self.range;
+   }
+
+   /// Gets whether the date's month was
+   /// misspelled and recovered by fuzzy
+   /// month matching.
+   pub fn is_fuzzy(
+      & self,
+   ) -> bool {
+      return self.fuzzy;
+   }
+
+   /// Gets how likely the date is to really
+   /// be a date, from 0.0 to 1.0.
+   pub fn confidence(
+      & self,
+   ) -> f64 {
+      return self.confidence;
+   }
+
+   /// Gets what the date describes, such
+   /// as when the file was created.
+   pub fn category(
+      & self,
+   ) -> crate::category::DateCategory {
+      return self.category;
+   }
+
+   /// Gets the author written next to
+   /// the date, if found.
+   pub fn author<'l>(
+      &'l self,
+   ) -> Option<&'l str> {
+      return self.author.as_deref();
+   }
+
+   /// Gets the length in bytes of the text
+   /// the date was found in, or zero if
+   /// unknown.
+   pub fn length(
+      & self,
+   ) -> usize {
+      return self.length;
+   }
+}
+
+///////////////////////////////////////
+// Trait implementations - FoundDate //
+///////////////////////////////////////
+
+impl std::cmp::PartialEq for FoundDate {
+   fn eq(
+      & self,
+      other : & Self,
+   ) -> bool {
+      return self.date == other.date && self.offset == other.offset;
+   }
+}
+
+impl std::cmp::PartialEq<crate::date::Date> for FoundDate {
+   fn eq(
+      & self,
+      other : & crate::date::Date,
+   ) -> bool {
+      return self.date.eq(other);
+   }
+}
+
+impl std::cmp::PartialOrd for FoundDate {
+   fn partial_cmp(
+      & self,
+      other : & Self,
+   ) -> Option<std::cmp::Ordering> {
+      use std::cmp::Ordering::*;
+
+      // Dates found at the same date are
+      // ordered by where they were found
+      return match self.date.partial_cmp(&other.date)? {
+         Greater  => Some(Greater),
+         Less     => Some(Less),
+         Equal    => self.offset.partial_cmp(&other.offset),
+      };
+   }
+}
+
+impl std::cmp::Eq for FoundDate {
+}
+
+impl std::cmp::Ord for FoundDate {
+   fn cmp(
+      & self,
+      other : & Self,
+   ) -> std::cmp::Ordering {
+      use std::cmp::Ordering::*;
+
+      return self.partial_cmp(other).unwrap_or(Equal);
+   }
+}
+
+impl std::fmt::Display for FoundDate {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.date);
+   }
+}
+
 ////////////////////////
 // Methods - DateList //
 ////////////////////////
 
 impl DateList {
    /// Creates a new DateList from
-   /// an existing sorted list.
+   /// an existing sorted list.  The
+   /// locations of the dates are
+   /// unknown.
    pub fn from(
       list : sorted_vec::SortedVec<crate::date::Date>,
+   ) -> Self {
+      return Self::from_found(sorted_vec::SortedVec::from_unsorted(
+         list.iter().map(|date| FoundDate::new(*date, None)).collect(),
+      ));
+   }
+
+   /// Creates a new DateList from an
+   /// existing sorted list of found dates.
+   pub fn from_found(
+      list : sorted_vec::SortedVec<FoundDate>,
    ) -> Self {
       return Self{
          list  : list,
@@ -169,7 +566,7 @@ impl DateList {
    /// data slice.
    pub fn as_slice<'l>(
       &'l self,
-   ) -> &'l [crate::date::Date] {
+   ) -> &'l [FoundDate] {
       return &self.list;
    }
 
@@ -187,7 +584,7 @@ impl DateList {
 //////////////////////////////////////
 
 impl std::ops::Deref for DateList {
-   type Target = [crate::date::Date];
+   type Target = [FoundDate];
 
    fn deref(
       & self,
@@ -217,10 +614,10 @@ impl std::cmp::PartialOrd for DateList {
       // equal, they compare the newest date.
       // If they are still equal, compare the
       // element count.
-      return match self.first()?.partial_cmp(other.first()?)? {
+      return match self.first()?.date().partial_cmp(&other.first()?.date())? {
          Greater  => Some(Greater),
          Less     => Some(Less),
-         Equal    => self.last()?.partial_cmp(other.last()?),
+         Equal    => self.last()?.date().partial_cmp(&other.last()?.date()),
       };
    }
 }
@@ -266,7 +663,7 @@ impl<'l> DateListIterator<'l> {
 //////////////////////////////////////////////
 
 impl<'l> std::iter::Iterator for DateListIterator<'l> {
-   type Item = &'l crate::date::Date;
+   type Item = &'l FoundDate;
 
    fn next(
       & mut self,
@@ -291,8 +688,10 @@ impl FileDateList {
       dates : DateList,
    ) -> Self {
       return Self{
-         path  : path,
-         dates : dates,
+         path        : path,
+         dates       : dates,
+         rejected    : Vec::new(),
+         changelog   : Vec::new(),
       };
    }
    
@@ -300,41 +699,265 @@ impl FileDateList {
    /// a path buffer and reads the file
    /// at the location, constructing a
    /// new DateList.  Dates are searched
-   /// for using a given regular expression.
-   /// See crate::Date for more information
+   /// for using a given extractor, such as
+   /// a regular expression.  See crate::Date
+   /// and crate::extract for more information
    /// about the format of the regular expression.
-   pub fn from_file_with(
-      path  : std::path::PathBuf,
-      regex : & regex::Regex,
-   ) -> Result<Self> {
+   pub fn from_file_with<E>(
+      path        : std::path::PathBuf,
+      extractor   : & E,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      return Self::from_file_with_options(
+         path, extractor, &SearchOptions::default(),
+      );
+   }
+
+   /// Executes the same as Self::from_file_with,
+   /// but the file is searched using the given
+   /// search options.
+   pub fn from_file_with_options<E>(
+      path        : std::path::PathBuf,
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
       // Check if the file is a directory
       if std::fs::metadata(&path)?.is_dir() == true {
          return Err(CollectDateError::FileIsDirectory);
       }
 
-      // Map the file into memory as a string slice
+      // Map the file into memory and search it
       let file = std::fs::File::open(&path)?;
       let file = unsafe{memmap2::Mmap::map(&file)}?;
-      let file = match std::str::from_utf8(&file) {
+
+      return Self::from_bytes_with_options(path, &file, extractor, options);
+   }
+
+   /// Creates a new FileDateList by reading
+   /// a stream to its end and searching the
+   /// read data for dates.  The given path is
+   /// only used to label the data and is never
+   /// opened, which allows for pseudo-files such
+   /// as standard input.  See Self::from_file_with
+   /// for more information.
+   pub fn from_reader_with<R, E>(
+      path        : std::path::PathBuf,
+      reader      : R,
+      extractor   : & E,
+   ) -> Result<Self>
+   where R: std::io::Read,
+         E: crate::extract::Extract + ?Sized {
+      return Self::from_reader_with_options(
+         path, reader, extractor, &SearchOptions::default(),
+      );
+   }
+
+   /// Executes the same as Self::from_reader_with,
+   /// but the data is searched using the given
+   /// search options.
+   pub fn from_reader_with_options<R, E>(
+      path        : std::path::PathBuf,
+      mut reader  : R,
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<Self>
+   where R: std::io::Read,
+         E: crate::extract::Extract + ?Sized {
+      let mut buffer = Vec::new();
+      reader.read_to_end(&mut buffer)?;
+
+      return Self::from_bytes_with_options(path, &buffer, extractor, options);
+   }
+
+   /// Creates a new FileDateList from a byte
+   /// slice containing UTF-8 text.  If the data
+   /// is not valid UTF-8, an error is returned.
+   /// See Self::from_text_with for more information.
+   pub fn from_bytes_with<E>(
+      path        : std::path::PathBuf,
+      bytes       : & [u8],
+      extractor   : & E,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      return Self::from_bytes_with_options(
+         path, bytes, extractor, &SearchOptions::default(),
+      );
+   }
+
+   /// Executes the same as Self::from_bytes_with,
+   /// but the data is searched using the given
+   /// search options.  Compressed data is
+   /// decompressed first if enabled.  If binary
+   /// string searching is enabled, data which is
+   /// not valid UTF-8 has its printable strings
+   /// searched instead.
+   pub fn from_bytes_with_options<E>(
+      path        : std::path::PathBuf,
+      bytes       : & [u8],
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      // Decompress the data if it is compressed
+      let decompressed = match options.decompress {
+         true  => crate::decompress::decompress_file(&path, bytes, options.size_limit)?,
+         false => None,
+      };
+      let bytes = match &decompressed {
+         Some(d)  => d.as_slice(),
+         None     => bytes,
+      };
+
+      let text = match std::str::from_utf8(bytes) {
          Ok(d)    => d,
-         Err(_)   => return Err(CollectDateError::InvalidData),
+         Err(_)   => match options.binary_strings {
+            true  => return Self::from_binary_with_options(
+               path, bytes, extractor, options,
+            ),
+            false => return Err(CollectDateError::InvalidData),
+         },
+      };
+
+      return Self::from_text_with_options(path, text, extractor, options);
+   }
+
+   /// Creates a new FileDateList by searching
+   /// the printable strings inside of binary
+   /// data for dates.  The offset of each date
+   /// is its byte offset within the binary data.
+   /// See crate::strings for more information.
+   pub fn from_binary_with<E>(
+      path        : std::path::PathBuf,
+      bytes       : & [u8],
+      extractor   : & E,
+      min_length  : usize,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      return Self::from_binary_with_options(path, bytes, extractor, &SearchOptions{
+         min_string_length : min_length,
+         ..SearchOptions::default()
+      });
+   }
+
+   /// Executes the same as Self::from_binary_with,
+   /// but the minimum string length is taken from
+   /// the given search options, and rejected
+   /// matches are recorded if enabled.
+   pub fn from_binary_with_options<E>(
+      path        : std::path::PathBuf,
+      bytes       : & [u8],
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      let mut dates     = Vec::new();
+      let mut rejected  = Vec::new();
+      for string in crate::strings::extract(bytes, options.min_string_length) {
+         let (found, found_rejected) = Self::internal_find_all(
+            string.text(), extractor, options,
+         )?;
+
+         dates.extend(found.into_iter().map(|date| {
+            date.with_offset(date.offset().map(|offset| string.data_offset(offset)))
+         }));
+         rejected.extend(found_rejected.into_iter().map(|rejection| {
+            rejection.with_offset(string.data_offset(rejection.offset()))
+         }));
+      }
+
+      return Ok(Self{
+         path        : path,
+         dates       : DateList::from_found(sorted_vec::SortedVec::from_unsorted(dates)),
+         rejected    : rejected,
+         changelog   : Vec::new(),
+      });
+   }
+
+   /// Creates a new FileDateList by searching
+   /// a text string for dates using a given
+   /// extractor, such as a regular expression.
+   /// The given path is stored as-is to label
+   /// the data.
+   pub fn from_text_with<E>(
+      path        : std::path::PathBuf,
+      text        : & str,
+      extractor   : & E,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      return Self::from_text_with_options(
+         path, text, extractor, &SearchOptions::default(),
+      );
+   }
+
+   /// Executes the same as Self::from_text_with,
+   /// but rejected matches and changelog entries
+   /// are recorded if enabled in the given search
+   /// options.  If only comments are searched,
+   /// the offset of each date is its byte offset
+   /// within the whole text.
+   pub fn from_text_with_options<E>(
+      path        : std::path::PathBuf,
+      text        : & str,
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<Self>
+   where E: crate::extract::Extract + ?Sized {
+      // Choose the comment syntax if only comments are searched
+      let syntax = match options.comments_only {
+         true  => crate::comments::CommentSyntax::from_path(&path),
+         false => None,
+      };
+
+      // Find all dates within the text or its comments
+      let (dates, rejected) = match syntax {
+         Some(syntax)   => {
+            let mut dates     = Vec::new();
+            let mut rejected  = Vec::new();
+            for range in syntax.comment_ranges(text) {
+               let (found, found_rejected) = Self::internal_find_all(
+                  &text[range.clone()], extractor, options,
+               )?;
+
+               dates.extend(found.into_iter().map(|date| {
+                  date.with_offset(date.offset().map(|offset| range.start + offset))
+               }));
+               rejected.extend(found_rejected.into_iter().map(|rejection| {
+                  rejection.with_offset(range.start + rejection.offset())
+               }));
+            }
+            (dates, rejected)
+         },
+         None           => Self::internal_find_all(text, extractor, options)?,
       };
 
-      // Find all dates within the file
-      let dates = crate::date::Date::from_text_multi_sorted_with(
-         file, regex,
-      ).map_err(|_| CollectDateError::InvalidRegexCaptures)?;
+      // Parse revision history blocks
+      let changelog = match options.changelog {
+         true  => crate::changelog::find_entries(text, &dates),
+         false => Vec::new(),
+      };
       
       // Construct a DateList struct
-      let dates = DateList::from(dates);
+      let dates = DateList::from_found(sorted_vec::SortedVec::from_unsorted(dates));
 
       // Return success
       return Ok(Self{
-         path  : path,
-         dates : dates,
+         path        : path,
+         dates       : dates,
+         rejected    : rejected,
+         changelog   : changelog,
       });
    }
 
+   /// Get a reference to the file's
+   /// changelog entries, in the order
+   /// they appear in the file.
+   pub fn changelog<'l>(
+      &'l self,
+   ) -> &'l [crate::changelog::ChangelogEntry] {
+      return &self.changelog;
+   }
+
    /// Get a reference to the file's
    /// path.
    pub fn path<'l>(
@@ -350,6 +973,50 @@ impl FileDateList {
    ) -> &'l DateList {
       return &self.dates;
    }
+
+   /// Get a reference to the matches in the
+   /// file which were rejected because they
+   /// aren't valid dates.  This is only
+   /// populated if near misses are enabled
+   /// in the search options.
+   pub fn rejected<'l>(
+      &'l self,
+   ) -> &'l [crate::extract::RejectedMatch] {
+      return &self.rejected;
+   }
+}
+
+/////////////////////////////////////
+// Internal helpers - FileDateList //
+/////////////////////////////////////
+
+impl FileDateList {
+   /// Finds every date within a text string
+   /// with at least the minimum confidence,
+   /// classified using the keyword table,
+   /// along with the rejected matches if near
+   /// misses are enabled.
+   fn internal_find_all<E>(
+      text        : & str,
+      extractor   : & E,
+      options     : & SearchOptions,
+   ) -> Result<(Vec<FoundDate>, Vec<crate::extract::RejectedMatch>)>
+   where E: crate::extract::Extract + ?Sized {
+      let found = match options.near_misses {
+         true  => extractor.find_all_with_rejected(text),
+         false => extractor.find_all(text).map(|dates| (dates, Vec::new())),
+      };
+      let (mut dates, rejected) = found.map_err(|_| CollectDateError::InvalidRegexCaptures)?;
+
+      dates.retain(|date| date.confidence() >= options.min_confidence);
+      for date in dates.iter_mut() {
+         if let Some(offset) = date.offset() {
+            *date = date.with_category(options.keywords.classify(text, offset));
+         }
+      }
+
+      return Ok((dates, rejected));
+   }
 }
 
 //////////////////////////////////////////
@@ -410,10 +1077,18 @@ impl FileAggregateDateList {
    fn internal_search_dir_recursive<F>(
       thread_pool             : & mut DateFinderThreadPool,
       path                    : std::path::PathBuf,
+      visited                 : & mut std::collections::HashSet<std::path::PathBuf>,
       mut current_file_count  : usize,
       per_file                : F,
    ) -> Result<usize>
    where F: Fn(& std::path::Path) + Copy {
+      // Skip anything which was already searched
+      // through another input root or symlink
+      let canonical = std::fs::canonicalize(&path).unwrap_or_else(|_| path.clone());
+      if visited.insert(canonical) == false {
+         return Ok(current_file_count);
+      }
+
       // Check if the input file is a directory
       if std::fs::metadata(&path)?.is_dir() {
          // Iterate for every element in the directory
@@ -424,10 +1099,21 @@ impl FileAggregateDateList {
             current_file_count = Self::internal_search_dir_recursive(
                thread_pool,
                path,
+               visited,
                current_file_count,
                per_file.clone(),
             )?;
          }
+      } else if thread_pool.options().archives && Self::internal_is_archive(&path)? {
+         // Execute the user closure
+         per_file(&path);
+
+         // Send the archive to the thread pool to
+         // have its members read and parsed
+         thread_pool.send_archive(path);
+
+         // Increment the file count
+         current_file_count += 1;
       } else {
          // Execute the user closure
          per_file(&path);
@@ -442,6 +1128,21 @@ impl FileAggregateDateList {
       // Return success
       return Ok(current_file_count);
    }
+
+   /// Reads the start of a file to check
+   /// whether it may be an archive.
+   fn internal_is_archive(
+      path  : & std::path::Path,
+   ) -> Result<bool> {
+      use std::io::Read;
+
+      let mut header = Vec::with_capacity(crate::archive::HEADER_LENGTH);
+      std::fs::File::open(path)?
+         .take(crate::archive::HEADER_LENGTH as u64)
+         .read_to_end(&mut header)?;
+
+      return Ok(crate::archive::may_be_archive(&header));
+   }
 }
 
 /////////////////////////////////////
@@ -477,62 +1178,110 @@ impl FileAggregateDateList {
    ) -> Result<Self>
    where P: AsRef<std::path::Path>,
          F: Fn(& std::path::Path) + Copy {
-      // Convert the path into a PathBuf
-      let mut path_buf = std::path::PathBuf::new();
-      path_buf.push(path);
+      return Self::new_recursive_multi_with(
+         thread_pool,
+         std::iter::once(path),
+         per_file,
+      );
+   }
 
-      // Create the buffer for holding file date lists
-      let mut file_list_buffer = sorted_vec::SortedVec::new();
+   /// Executes the same as Self::new_recursive_with,
+   /// but searches every file or directory in a list
+   /// of input roots and merges the results into a
+   /// single list.  Files reachable from more than
+   /// one root are only searched once.
+   pub fn new_recursive_multi_with<I, P, F>(
+      thread_pool : & mut DateFinderThreadPool,
+      paths       : I,
+      per_file    : F,
+   ) -> Result<Self>
+   where I: IntoIterator<Item = P>,
+         P: AsRef<std::path::Path>,
+         F: Fn(& std::path::Path) + Copy {
+      // Create the aggregate for holding file date lists
+      let mut aggregate = Self{
+         files    : sorted_vec::SortedVec::new(),
+         rejected : Vec::new(),
+      };
 
       // Assign file paths to the thread pool
-      let mut expected_file_count = Self::internal_search_dir_recursive(
-         thread_pool,
-         path_buf,
-         0,
-         per_file,
-      )?;
+      let mut visited = std::collections::HashSet::new();
+      let mut expected_file_count = 0;
+      for path in paths {
+         // Convert the path into a PathBuf
+         let mut path_buf = std::path::PathBuf::new();
+         path_buf.push(path);
+
+         expected_file_count = Self::internal_search_dir_recursive(
+            thread_pool,
+            path_buf,
+            & mut visited,
+            expected_file_count,
+            per_file,
+         )?;
+      }
 
-      // Start populating the file list buffer with results
-      while file_list_buffer.len() < expected_file_count {
-         let file_dates = match thread_pool.recv() {
+      // Start populating the aggregate with results
+      let mut received_file_count = 0;
+      while received_file_count < expected_file_count {
+         let file_dates_list = match thread_pool.recv() {
             Some(fd) => fd,
             None     => continue,
          };
-
-         // Unwrap error variant
-         let file_dates = match file_dates {
-            Ok(fd)   => fd,
-            Err(e)   => match e {
-               CollectDateError::InvalidData
-                  => {
-                     expected_file_count -= 1;
-                     continue;
-                  },
-               _
-                  => return Err(e),
-            },
-         };
-
-         // If the date list is empty, nix this file
-         // from the data
-         if file_dates.dates().is_empty() {
-            expected_file_count -= 1;
-            continue;
+         received_file_count += 1;
+
+         for file_dates in file_dates_list {
+            // Unwrap error variant
+            let file_dates = match file_dates {
+               Ok(fd)   => fd,
+               Err(e)   => match e {
+                  CollectDateError::InvalidData | CollectDateError::DataTooLarge
+                     => continue,
+                  _
+                     => return Err(e),
+               },
+            };
+
+            // Add the file dating to the list, which
+            // nixes files with an empty date list
+            aggregate.insert(file_dates);
          }
-
-         // Add the file dating to the list
-         file_list_buffer.push(file_dates);
       }
 
-      // Create the struct
-      let aggregate = Self{
-         files : file_list_buffer,
-      };
-
       // Return success
       return Ok(aggregate);
    }
 
+   /// Inserts a single FileDateList into the
+   /// list, keeping the list sorted.  Files
+   /// with zero found dates are discarded,
+   /// but their rejected matches are kept.
+   pub fn insert(
+      & mut self,
+      file  : FileDateList,
+   ) -> & mut Self {
+      if file.rejected().is_empty() == false {
+         let index = self.rejected.partition_point(|(path, _)| path.as_path() < file.path());
+         self.rejected.insert(index, (file.path().to_path_buf(), file.rejected().to_vec()));
+      }
+      if file.dates().is_empty() == false {
+         self.files.push(file);
+      }
+
+      return self;
+   }
+
+   /// Gets every file path with matches which
+   /// were rejected because they aren't valid
+   /// dates, sorted by path.  This is only
+   /// populated if near misses are enabled
+   /// in the search options.
+   pub fn rejected<'l>(
+      &'l self,
+   ) -> &'l [(std::path::PathBuf, Vec<crate::extract::RejectedMatch>)] {
+      return &self.rejected;
+   }
+
    /// Accesses the underlying data as
    /// a FileDateList slice.  This is
    /// equivalent to the Deref trait
@@ -551,6 +1300,22 @@ impl FileAggregateDateList {
       return FileAggregateDateListIterator::new(self);
    }
 
+   /// Gets every file sorted in the given
+   /// order, optionally reversed, comparing
+   /// only their dates in the given category
+   /// if any.  See crate::sort::SortOrder for
+   /// more information.
+   pub fn sorted_by<'l>(
+      &'l self,
+      order    : crate::sort::SortOrder,
+      reverse  : bool,
+      category : Option<crate::category::DateCategory>,
+   ) -> Vec<&'l FileDateList> {
+      let mut files = self.iter().collect::<Vec<_>>();
+      order.sort(& mut files, reverse, category);
+      return files;
+   }
+
    /// Creates a FileAggregateReport from
    /// the data.
    pub fn create_report<'l>(
@@ -558,6 +1323,18 @@ impl FileAggregateDateList {
    ) -> crate::report::Result<crate::report::FileAggregateReport<'l>> {
       return crate::report::FileAggregateReport::from(self);
    }
+
+   /// Creates a report which compares files
+   /// using only the dates in the given
+   /// category.  See
+   /// crate::report::FileAggregateReport::from_category
+   /// for more information.
+   pub fn create_report_for<'l>(
+      &'l self,
+      category : crate::category::DateCategory,
+   ) -> crate::report::Result<crate::report::FileAggregateReport<'l>> {
+      return crate::report::FileAggregateReport::from_category(self, Some(category));
+   }
 }
 
 ///////////////////////////////////////////////////
@@ -574,6 +1351,21 @@ impl std::ops::Deref for FileAggregateDateList {
    }
 }
 
+impl std::iter::FromIterator<FileDateList> for FileAggregateDateList {
+   /// Collects already-searched files into a
+   /// FileAggregateDateList without any
+   /// rejected matches.
+   fn from_iter<I>(
+      iter : I,
+   ) -> Self
+   where I: IntoIterator<Item = FileDateList> {
+      return Self{
+         files    : sorted_vec::SortedVec::from_unsorted(iter.into_iter().collect()),
+         rejected : Vec::new(),
+      };
+   }
+}
+
 /////////////////////////////////////////////
 // Methods - FileAggregateDateListIterator //
 /////////////////////////////////////////////
@@ -608,21 +1400,60 @@ impl<'l> std::iter::Iterator for FileAggregateDateListIterator<'l> {
    }
 }
 
+/////////////////////////////////////////////
+// Internal helpers - DateFinderThreadPool //
+/////////////////////////////////////////////
+
+impl DateFinderThreadPool {
+   /// Sends a job to the next thread in
+   /// the pool.
+   fn internal_send_job(
+      & mut self,
+      job   : DateFinderJob,
+   ) -> & mut Self {
+      self.pipe_send_list[self.thread_send_next].send(job).expect(
+         "Broken incoming pipe",
+      );
+
+      self.thread_send_next =
+         (self.thread_send_next + 1) % self.pipe_send_list.len();
+      return self;
+   }
+}
+
 ////////////////////////////////////
 // Methods - DateFinderThreadPool //
 ////////////////////////////////////
 
 impl DateFinderThreadPool {
    /// Creates a new thread pool with the
-   /// given number of threads.  A regular
-   /// expression to be used for searching
-   /// for dates is passed to each thread.
-   /// See crate::Date for more information
-   /// on the format of the regular expression.
-   pub fn new(
-      thread_count         : std::num::NonZeroUsize,
-      regular_expression   : regex::Regex,
-   ) -> Self {
+   /// given number of threads.  An extractor,
+   /// such as a regular expression, to be used
+   /// for searching for dates is cloned to each
+   /// thread.  See crate::Date and crate::extract
+   /// for more information on the format of the
+   /// regular expression.
+   pub fn new<E>(
+      thread_count   : std::num::NonZeroUsize,
+      extractor      : E,
+   ) -> Self
+   where E: crate::extract::Extract + Clone + Send + 'static {
+      return Self::new_with_options(
+         thread_count,
+         extractor,
+         SearchOptions::default(),
+      );
+   }
+
+   /// Executes the same as Self::new, but
+   /// files are searched using the given
+   /// search options.
+   pub fn new_with_options<E>(
+      thread_count   : std::num::NonZeroUsize,
+      extractor      : E,
+      options        : SearchOptions,
+   ) -> Self
+   where E: crate::extract::Extract + Clone + Send + 'static {
       // Initialize pipes
       let mut pipe_in_send_list = Vec::with_capacity(thread_count.get());
       let (
@@ -636,17 +1467,28 @@ impl DateFinderThreadPool {
             pipe_in_send,
             pipe_in_recv,
          ) = std::sync::mpsc::channel();
-         let pipe_out_send = pipe_out_send.clone();
-         let regex_thread  = regular_expression.clone();
+         let pipe_out_send    = pipe_out_send.clone();
+         let extractor_thread = extractor.clone();
+         let options_thread   = options.clone();
 
          pipe_in_send_list.push(pipe_in_send);
          std::thread::spawn(move || {
-            let regex   = regex_thread;
-            let recv    = pipe_in_recv;
-            let send    = pipe_out_send;
-
-            while let Ok(path) = recv.recv() {
-               send.send(FileDateList::from_file_with(path, &regex)).expect(
+            let extractor  = extractor_thread;
+            let options    = options_thread;
+            let recv       = pipe_in_recv;
+            let send       = pipe_out_send;
+
+            while let Ok(job) = recv.recv() {
+               let file_dates = match job {
+                  DateFinderJob::File(path)
+                     => vec![FileDateList::from_file_with_options(path, &extractor, &options)],
+                  DateFinderJob::Buffer(path, data)
+                     => vec![FileDateList::from_bytes_with_options(path, &data, &extractor, &options)],
+                  DateFinderJob::Archive(path)
+                     => internal_search_archive(path, &extractor, &options),
+               };
+
+               send.send(file_dates).expect(
                   "Broken outgoing pipe",
                );
             }
@@ -660,34 +1502,63 @@ impl DateFinderThreadPool {
          pipe_send_list    : pipe_in_send_list,
          pipe_recv         : pipe_out_recv,
          thread_send_next  : 0,
+         options           : options,
       };
    }
 
+   /// Gets a reference to the options
+   /// used for searching files.
+   pub fn options<'l>(
+      &'l self,
+   ) -> &'l SearchOptions {
+      return &self.options;
+   }
+
    /// Send a PathBuf to a file to be searched
    /// for dates.
    pub fn send(
       & mut self,
       path  : std::path::PathBuf,
    ) -> & mut Self {
-      self.pipe_send_list[self.thread_send_next].send(path).expect(
-         "Broken incoming pipe",
-      );
+      return self.internal_send_job(DateFinderJob::File(path));
+   }
 
-      self.thread_send_next =
-         (self.thread_send_next + 1) % self.pipe_send_list.len();
-      return self;
+   /// Send data already in memory to be
+   /// searched for dates.  The path is only
+   /// used to label the resulting FileDateList.
+   pub fn send_buffer(
+      & mut self,
+      path  : std::path::PathBuf,
+      data  : Vec<u8>,
+   ) -> & mut Self {
+      return self.internal_send_job(DateFinderJob::Buffer(path, data));
+   }
+
+   /// Send a PathBuf to an archive whose
+   /// members are each searched for dates.
+   /// If the file isn't an archive, it's
+   /// searched like any other file.  See
+   /// crate::archive for more information.
+   pub fn send_archive(
+      & mut self,
+      path  : std::path::PathBuf,
+   ) -> & mut Self {
+      return self.internal_send_job(DateFinderJob::Archive(path));
    }
 
-   /// Receives a DateList created from a file.
-   /// If there are currently no avaliable dates,
-   /// None is returned.  It is recommended to keep
-   /// a counter of how many files were sent and to
+   /// Receives the DateLists created from a
+   /// sent file, which is a single DateList
+   /// for files and buffers, or one for each
+   /// member of an archive.  If there are
+   /// currently no avaliable dates, None is
+   /// returned.  It is recommended to keep a
+   /// counter of how many files were sent and to
    /// keep requesting data until the requested data
    /// count matches the sent file count.  Otherwise,
    /// dates may be missed.
    pub fn recv(
       & mut self,
-   ) -> Option<Result<FileDateList>> {
+   ) -> Option<Vec<Result<FileDateList>>> {
       use std::sync::mpsc::TryRecvError;
 
       return match self.pipe_recv.try_recv() {
@@ -702,3 +1573,105 @@ impl DateFinderThreadPool {
    }
 }
 
+///////////////
+// Functions //
+///////////////
+
+/// Reads a list of file paths from a stream,
+/// such as the output of "find -print0" or
+/// "git ls-files".  If the data contains a NUL
+/// character, paths are delimited by NUL,
+/// otherwise paths are delimited by newlines.
+/// Empty entries are skipped.
+pub fn read_path_list<R>(
+   mut reader : R,
+) -> Result<Vec<std::path::PathBuf>>
+where R: std::io::Read {
+   let mut buffer = Vec::new();
+   reader.read_to_end(&mut buffer)?;
+
+   // Choose the delimiter based on the data
+   let delimiter = if buffer.contains(&b'\0') {
+      b'\0'
+   } else {
+      b'\n'
+   };
+
+   let mut paths = Vec::new();
+   for entry in buffer.split(|c| *c == delimiter) {
+      // Strip carriage returns from newline-delimited lists
+      let entry = match (delimiter, entry.last()) {
+         (b'\n', Some(b'\r'))  => &entry[..entry.len() - 1],
+         _                     => entry,
+      };
+      if entry.is_empty() {
+         continue;
+      }
+
+      paths.push(internal_path_from_bytes(entry)?);
+   }
+
+   return Ok(paths);
+}
+
+/// Reads every member of an archive and
+/// searches each one for dates.  Files which
+/// aren't archives, including archives which
+/// can't be opened, are searched like any other
+/// file, reusing the data if it was already
+/// decompressed.
+fn internal_search_archive<E>(
+   path        : std::path::PathBuf,
+   extractor   : & E,
+   options     : & SearchOptions,
+) -> Vec<Result<FileDateList>>
+where E: crate::extract::Extract + ?Sized {
+   use crate::archive::ArchiveData;
+
+   let data = match std::fs::read(&path) {
+      Ok(d)    => d,
+      Err(e)   => return vec![Err(e.into())],
+   };
+
+   let mut file_dates = Vec::new();
+   let read = crate::archive::read_members(&path, &data, options.size_limit, & mut |member, data| {
+      file_dates.push(FileDateList::from_bytes_with_options(member, &data, extractor, options));
+   });
+
+   let data = match read {
+      Ok(ArchiveData::Archive)
+         => return file_dates,
+      Ok(ArchiveData::Plain(Some(decompressed))) if options.decompress
+         => decompressed,
+      Ok(ArchiveData::Plain(_)) | Err(_)
+         => data,
+   };
+   file_dates.push(FileDateList::from_bytes_with_options(path, &data, extractor, options));
+
+   return file_dates;
+}
+
+/// Converts raw bytes into a PathBuf.  On
+/// Unix systems, paths may contain arbitrary
+/// bytes, otherwise they must be UTF-8.
+#[cfg(unix)]
+fn internal_path_from_bytes(
+   bytes : & [u8],
+) -> Result<std::path::PathBuf> {
+   use std::os::unix::ffi::OsStrExt;
+
+   return Ok(std::path::PathBuf::from(std::ffi::OsStr::from_bytes(bytes)));
+}
+
+/// Converts raw bytes into a PathBuf.  On
+/// Unix systems, paths may contain arbitrary
+/// bytes, otherwise they must be UTF-8.
+#[cfg(not(unix))]
+fn internal_path_from_bytes(
+   bytes : & [u8],
+) -> Result<std::path::PathBuf> {
+   return match std::str::from_utf8(bytes) {
+      Ok(s)    => Ok(std::path::PathBuf::from(s)),
+      Err(_)   => Err(CollectDateError::InvalidData),
+   };
+}
@@ -0,0 +1,309 @@
+//! Utilities for finding the comments inside
+//! of source code.
+//!
+//! The comment syntax of a file is chosen by its
+//! extension or name, and the byte ranges of its
+//! comments are found so only they are searched
+//! for dates.  String literals are skipped so
+//! comment markers inside of them, such as the
+//! "//" in a URL, aren't mistaken for comments.
+//! Rust has its own syntax, as its lifetimes
+//! look like unclosed character literals and
+//! its block comments may be nested.
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// The comment syntax of a programming
+/// language.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CommentSyntax {
+   /// C-style "//" line comments and
+   /// "/* */" block comments, used by C,
+   /// C++, and similar languages.
+   C,
+
+   /// Rust "//" line comments and nested
+   /// "/* */" block comments, skipping raw
+   /// strings and lifetimes.
+   Rust,
+
+   /// Assembly ";" line comments.
+   Assembly,
+
+   /// "#" line comments, used by shell
+   /// scripts, Python, and Makefiles.
+   Hash,
+
+   /// Lua "--" line comments and "--[[ ]]"
+   /// block comments.
+   Lua,
+}
+
+///////////////
+// Constants //
+///////////////
+
+const C_EXTENSIONS : [&str; 18] = [
+   "c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx", "inl", "m",
+   "mm", "cs", "java", "js", "ts", "go", "swift", "kt",
+];
+
+const ASSEMBLY_EXTENSIONS : [&str; 3] = [
+   "s", "asm", "inc",
+];
+
+const HASH_EXTENSIONS : [&str; 12] = [
+   "sh", "bash", "zsh", "py", "pl", "rb", "mk", "mak",
+   "cmake", "yml", "yaml", "toml",
+];
+
+const HASH_FILE_NAMES : [&str; 5] = [
+   "makefile", "gnumakefile", "cmakelists.txt", "dockerfile", "configure",
+];
+
+/////////////////////////////
+// Methods - CommentSyntax //
+/////////////////////////////
+
+impl CommentSyntax {
+   /// Chooses the comment syntax of a file
+   /// from its extension or name, matched
+   /// case-insensitively.  Returns None if
+   /// the language of the file is unknown.
+   pub fn from_path(
+      path  : & std::path::Path,
+   ) -> Option<Self> {
+      let name = path.file_name()?.to_str()?.to_lowercase();
+      if HASH_FILE_NAMES.contains(&name.as_str()) {
+         return Some(Self::Hash);
+      }
+
+      let extension = path.extension()?.to_str()?.to_lowercase();
+      let extension = extension.as_str();
+      if C_EXTENSIONS.contains(&extension) {
+         return Some(Self::C);
+      }
+      if extension == "rs" {
+         return Some(Self::Rust);
+      }
+      if ASSEMBLY_EXTENSIONS.contains(&extension) {
+         return Some(Self::Assembly);
+      }
+      if HASH_EXTENSIONS.contains(&extension) {
+         return Some(Self::Hash);
+      }
+      if extension == "lua" {
+         return Some(Self::Lua);
+      }
+
+      return None;
+   }
+
+   /// Finds the byte range of every comment
+   /// within source code, including the comment
+   /// markers.  Ranges are returned in the order
+   /// they appear in the text.
+   pub fn comment_ranges(
+      & self,
+      text  : & str,
+   ) -> Vec<std::ops::Range<usize>> {
+      let (line, block, quotes) : (&str, Option<(&str, &str)>, &[u8]) = match self {
+         Self::C        => ("//", Some(("/*",   "*/")), b"\"'"),
+         Self::Rust     => ("//", Some(("/*",   "*/")), b""),
+         Self::Assembly => (";",  None,                 b"\""),
+         Self::Hash     => ("#",  None,                 b"\"'"),
+         Self::Lua      => ("--", Some(("--[[", "]]")), b"\"'"),
+      };
+
+      let bytes      = text.as_bytes();
+      let mut ranges = Vec::new();
+      let mut index  = 0;
+      while index < bytes.len() {
+         let rest = &bytes[index..];
+
+         // Block comments run until their end marker
+         if let Some((open, close)) = block {
+            if rest.starts_with(open.as_bytes()) {
+               let end = self.internal_block_end(bytes, index + open.len(), open, close);
+
+               ranges.push(index..end);
+               index = end;
+               continue;
+            }
+         }
+
+         // Line comments run until the end of the line
+         if rest.starts_with(line.as_bytes()) && self.internal_starts_comment(bytes, index) {
+            let end = text[index..].find('\n')
+               .map(|e| index + e)
+               .unwrap_or(bytes.len());
+
+            ranges.push(index..end);
+            index = end;
+            continue;
+         }
+
+         // Skip Rust string and character literals
+         if *self == Self::Rust {
+            if let Some(end) = internal_rust_literal_end(bytes, index) {
+               index = end;
+               continue;
+            }
+         }
+
+         // Skip string literals up to their closing
+         // quote or the end of the line
+         if quotes.contains(&bytes[index]) {
+            let quote = bytes[index];
+            index += 1;
+            while index < bytes.len() && bytes[index] != quote && bytes[index] != b'\n' {
+               if bytes[index] == b'\\' {
+                  index += 1;
+               }
+               index += 1;
+            }
+         }
+
+         index += 1;
+      }
+
+      return ranges;
+   }
+}
+
+//////////////////////////////////////
+// Internal helpers - CommentSyntax //
+//////////////////////////////////////
+
+impl CommentSyntax {
+   /// Checks whether a line comment marker at
+   /// the given byte index starts a comment.
+   /// Hash comments must start a word, so
+   /// shell expansions such as "$#" and "${#x}"
+   /// aren't mistaken for comments.
+   fn internal_starts_comment(
+      & self,
+      bytes : & [u8],
+      index : usize,
+   ) -> bool {
+      return match self {
+         Self::Hash  => index == 0 || bytes[index - 1].is_ascii_whitespace() || bytes[index - 1] == b';',
+         _           => true,
+      };
+   }
+
+   /// Finds the byte index just past the end
+   /// marker of a block comment whose contents
+   /// start at the given byte index.  Rust
+   /// block comments may be nested.  Unclosed
+   /// block comments run to the end of the text.
+   fn internal_block_end(
+      & self,
+      bytes : & [u8],
+      start : usize,
+      open  : & str,
+      close : & str,
+   ) -> usize {
+      let mut depth  = 1;
+      let mut index  = start;
+      while index < bytes.len() {
+         let rest = &bytes[index..];
+         if rest.starts_with(close.as_bytes()) {
+            depth -= 1;
+            index += close.len();
+            if depth == 0 {
+               return index;
+            }
+         } else if *self == Self::Rust && rest.starts_with(open.as_bytes()) {
+            depth += 1;
+            index += open.len();
+         } else {
+            index += 1;
+         }
+      }
+
+      return bytes.len();
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Finds the byte index just past the end of
+/// a Rust string or character literal starting
+/// at the given byte index, or None if there
+/// is no literal there.  Strings may span
+/// lines, raw strings such as r#"..."# have no
+/// escapes, and a quote which doesn't close a
+/// character literal starts a lifetime, which
+/// is skipped alone.
+fn internal_rust_literal_end(
+   bytes : & [u8],
+   index : usize,
+) -> Option<usize> {
+   let rest = &bytes[index..];
+   let after_identifier = index > 0 && (bytes[index - 1].is_ascii_alphanumeric() || bytes[index - 1] == b'_');
+
+   // Raw strings, optionally as byte strings
+   let prefix = match rest {
+      [b'r', ..]        => 1,
+      [b'b', b'r', ..]  => 2,
+      _                 => 0,
+   };
+   if prefix != 0 && after_identifier == false {
+      let hashes = rest[prefix..].iter().take_while(|c| **c == b'#').count();
+      if rest.get(prefix + hashes) == Some(&b'"') {
+         let body     = index + prefix + hashes + 1;
+         let mut end  = body;
+         while end < bytes.len() {
+            if bytes[end] == b'"' && bytes[end + 1..].iter().take(hashes).filter(|c| **c == b'#').count() == hashes {
+               return Some(end + 1 + hashes);
+            }
+            end += 1;
+         }
+         return Some(bytes.len());
+      }
+   }
+
+   return match rest {
+      // Strings, which may span lines
+      [b'"', ..] => {
+         let mut end = index + 1;
+         while end < bytes.len() && bytes[end] != b'"' {
+            if bytes[end] == b'\\' {
+               end += 1;
+            }
+            end += 1;
+         }
+         Some((end + 1).min(bytes.len()))
+      },
+
+      // Escaped character literals such as '\n' or '\u{2026}'
+      [b'\'', b'\\', ..] => {
+         let end = bytes.get(index + 3..).unwrap_or(&[]).iter().position(|c| *c == b'\'' || *c == b'\n')
+            .map(|e| index + 3 + e + 1)
+            .unwrap_or(bytes.len());
+         Some(end)
+      },
+
+      // Character literals, otherwise a lifetime
+      [b'\'', first, ..] => {
+         let length = match first {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            _           => 4,
+         };
+         match bytes.get(index + 1 + length) {
+            Some(b'\'')   => Some(index + 2 + length),
+            _              => Some(index + 1),
+         }
+      },
+
+      _ => None,
+   };
+}
+
@@ -0,0 +1,155 @@
+//! Utilities for scoring how likely a regular
+//! expression match is to really be a date.
+//!
+//! Not every match is a date; "May 5 2000" could
+//! be prose, and a version string such as "Mar 3 1"
+//! parses as the year 1.  A confidence score from
+//! 0.0 to 1.0 is created from how strict the format
+//! which matched is and how plausible the year is,
+//! raised when the date is written inside of a
+//! comment or near a keyword such as "created" or
+//! "modified".
+
+///////////////
+// Constants //
+///////////////
+
+/// The strictness of a custom regular
+/// expression, which is unknown.
+pub const DEFAULT_STRICTNESS : f64 = 0.7;
+
+/// Keywords which commonly introduce a date,
+/// matched case-insensitively.
+pub const KEYWORDS : [&str; 12] = [
+   "created", "modified", "updated", "revised",
+   "date:", "dated", "written", "compiled",
+   "built", "released", "copyright", "changed",
+];
+
+/// The amount of bytes before a date which
+/// are searched for a keyword.
+pub const KEYWORD_WINDOW : usize = 48;
+
+/// Text which starts a comment in common
+/// programming and markup languages.  A
+/// leading "*" only continues a C-style block
+/// comment, so it is checked separately with
+/// in_block_comment.
+const COMMENT_MARKERS : [&str; 6] = [
+   "//", "/*", "#", ";", "--", "<!--",
+];
+
+const KEYWORD_BONUS : f64 = 0.15;
+const COMMENT_BONUS : f64 = 0.1;
+
+///////////////
+// Functions //
+///////////////
+
+/// Scores how likely the date found at the
+/// given byte range of the text is to really
+/// be a date, from 0.0 to 1.0.  The strictness
+/// of the format which found the date is scaled
+/// by year_plausibility, with a bonus for
+/// a keyword on the same line shortly before
+/// the date and a bonus for dates inside of a
+/// comment, including lines continuing a
+/// C-style block comment with "*".
+pub fn score(
+   text        : & str,
+   span        : std::ops::Range<usize>,
+   date        : & crate::date::Date,
+   strictness  : f64,
+) -> f64 {
+   let line = internal_line_before(text, span.start);
+
+   let mut score = strictness * year_plausibility(date.year());
+   if has_keyword(line) {
+      score += KEYWORD_BONUS;
+   }
+   if is_comment(line) || (line.trim_start().starts_with('*') && in_block_comment(text, span.start)) {
+      score += COMMENT_BONUS;
+   }
+
+   return score.clamp(0.0, 1.0);
+}
+
+/// Scores how plausible a year is for a date
+/// written in a file, from 0.0 to 1.0.  Years
+/// from 1900 to 2100 are fully plausible, while
+/// years with less than three digits are most
+/// likely version numbers or other values.
+/// Years before 1 CE are the least plausible.
+pub fn year_plausibility(
+   year  : isize,
+) -> f64 {
+   return match year {
+      1900..=2100 => 1.0,
+      1000..=1899 => 0.8,
+      100 ..=999  => 0.5,
+      2101..      => 0.3,
+      1   ..=99   => 0.2,
+      ..=0        => 0.1,
+   };
+}
+
+/// Checks whether the text on a line before
+/// a date contains a keyword within the
+/// keyword window.
+pub fn has_keyword(
+   line  : & str,
+) -> bool {
+   let mut start = line.len().saturating_sub(KEYWORD_WINDOW);
+   while line.is_char_boundary(start) == false {
+      start -= 1;
+   }
+
+   let window = line[start..].to_lowercase();
+   return KEYWORDS.iter().any(|k| window.contains(k));
+}
+
+/// Checks whether the text on a line before
+/// a date starts or opens a comment.
+pub fn is_comment(
+   line  : & str,
+) -> bool {
+   let trimmed = line.trim_start();
+
+   return COMMENT_MARKERS.iter().any(|m| trimmed.starts_with(m))
+      || line.contains("//")
+      || line.contains("/*")
+      || line.contains("<!--");
+}
+
+/// Checks whether the given byte offset of
+/// the text is inside of an unclosed C-style
+/// "/*" block comment.
+pub fn in_block_comment(
+   text     : & str,
+   offset   : usize,
+) -> bool {
+   let before = &text[..offset];
+   return match before.rfind("/*") {
+      Some(open)  => before[open + 2..].contains("*/") == false,
+      None        => false,
+   };
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Gets the text from the start of the line
+/// containing the given byte offset up to
+/// the offset.
+fn internal_line_before<'l>(
+   text     : &'l str,
+   offset   : usize,
+) -> &'l str {
+   let before = &text[..offset];
+   return match before.rfind('\n') {
+      Some(i)  => &before[i + 1..],
+      None     => before,
+   };
+}
+
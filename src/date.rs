@@ -11,8 +11,15 @@ pub enum ParseMonthError {
    NoMatchingMonth,
 }
 
-/// Error type detailing a parsing error for Date.
+/// Error type detailing a parsing error for Weekday.
 #[derive(Copy, Clone, Debug)]
+pub enum ParseWeekdayError {
+   /// A weekday matching the input text was not found.
+   NoMatchingWeekday,
+}
+
+/// Error type detailing a parsing error for Date.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ParseDateError {
    /// The input string is not formatted as a date.
    InvalidFormatting,
@@ -31,6 +38,16 @@ pub enum ParseDateError {
 
    /// The given regex does not have M/D/Y captures.
    InvalidRegexCaptures,
+
+   /// The hour, minute, or second is not valid.
+   InvalidTimeOfDay,
+
+   /// The quarter is not between 1 and 4.
+   InvalidQuarter,
+
+   /// The end of a date range is not after
+   /// its start, or the range is too long.
+   InvalidRange,
 }
 
 /// Enum for storing a month.
@@ -50,14 +67,173 @@ pub enum Month {
    December,
 }
 
-// Struct for storing a date.
+/// Enum for storing a day of the week,
+/// starting from Monday as in ISO 8601.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Weekday {
+   Monday,
+   Tuesday,
+   Wednesday,
+   Thursday,
+   Friday,
+   Saturday,
+   Sunday,
+}
+
+/// Enum for storing how precisely a date
+/// is known, ordered from least to most
+/// precise.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Precision {
+   /// Only the year is known, such as "(c) 1995".
+   Year,
+
+   /// Only the quarter and year are known,
+   /// such as "Q3 1995".
+   Quarter,
+
+   /// Only the month and year are known,
+   /// such as "June 1995".
+   Month,
+
+   /// The full date is known.
+   Day,
+}
+
+/// Enum for storing the calendar and year
+/// numbering a date is written in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Calendar {
+   /// The proleptic Gregorian calendar with
+   /// years Before Common Era (BCE) and during
+   /// Common Era (CE).  There is no year zero,
+   /// so year -1 is 1 BCE.
+   Gregorian,
+
+   /// The Julian calendar with BCE and CE
+   /// years.  There is no year zero, so year
+   /// -1 is 1 BCE.
+   Julian,
+
+   /// The proleptic Gregorian calendar with
+   /// astronomical year numbering, where year
+   /// 0 is 1 BCE and year -1 is 2 BCE.
+   Astronomical,
+}
+
+/// Error type detailing a parsing error for Calendar.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseCalendarError {
+   /// A calendar matching the input text was not found.
+   NoMatchingCalendar,
+}
+
+/// Options controlling how dates are
+/// created from regular expression captures.
+/// The default options use the Gregorian
+/// calendar without fuzzy month matching.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+   /// The calendar the dates are written in.
+   pub calendar : Calendar,
+
+   /// The maximum edit distance used to recover
+   /// misspelled months, or None to disable fuzzy
+   /// month matching.  See Month::from_str_fuzzy.
+   pub fuzzy_months : Option<usize>,
+}
+
+/// Error type detailing a parsing error for TimeZone.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseTimeZoneError {
+   /// The text is not a UTC offset or known abbreviation.
+   NoMatchingTimeZone,
+}
+
+/// Struct for storing a time of day.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct Time {
+   hour   : usize,
+   minute : usize,
+   second : usize,
+}
+
+/// Struct for storing a time zone as an
+/// offset from UTC, optionally named by
+/// an abbreviation such as "JST".
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct TimeZone {
+   offset         : isize,
+   abbreviation   : Option<&'static str>,
+}
+
+// Struct for storing a date.  Partial
+// dates store the first day of the year,
+// quarter, or month they refer to along
+// with their precision.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct Date {
-   day   : usize,
-   month : Month,
-   year  : isize,
+   day         : usize,
+   month       : Month,
+   year        : isize,
+   precision   : Precision,
+   calendar    : Calendar,
+   time        : Option<Time>,
+   zone        : Option<TimeZone>,
 }
 
+/// Common misspellings of month names
+/// which are recovered by fuzzy matching.
+const MONTH_MISSPELLINGS : [(&str, Month); 16] = [
+   ("janurary",   Month::January   ),
+   ("januray",    Month::January   ),
+   ("feburary",   Month::February  ),
+   ("febuary",    Month::February  ),
+   ("febrary",    Month::February  ),
+   ("feburay",    Month::February  ),
+   ("apirl",      Month::April     ),
+   ("agust",      Month::August    ),
+   ("augest",     Month::August    ),
+   ("setember",   Month::September ),
+   ("septmber",   Month::September ),
+   ("setpember",  Month::September ),
+   ("ocotber",    Month::October   ),
+   ("novmber",    Month::November  ),
+   ("decmber",    Month::December  ),
+   ("decemeber",  Month::December  ),
+];
+
+/// Known time zone abbreviations and their
+/// offsets from UTC in minutes.  Ambiguous
+/// abbreviations use their most common
+/// meaning in English text.
+pub const TIME_ZONE_ABBREVIATIONS : [(&str, isize); 24] = [
+   ("UT",   0        ),
+   ("UTC",  0        ),
+   ("GMT",  0        ),
+   ("Z",    0        ),
+   ("WET",  0        ),
+   ("BST",  60       ),
+   ("CET",  60       ),
+   ("CEST", 120      ),
+   ("EET",  120      ),
+   ("MSK",  180      ),
+   ("IST",  330      ),
+   ("HKT",  480      ),
+   ("AWST", 480      ),
+   ("JST",  540      ),
+   ("KST",  540      ),
+   ("AEST", 600      ),
+   ("EST",  -300     ),
+   ("EDT",  -240     ),
+   ("CST",  -360     ),
+   ("CDT",  -300     ),
+   ("MST",  -420     ),
+   ("MDT",  -360     ),
+   ("PST",  -480     ),
+   ("PDT",  -420     ),
+];
+
 /////////////////////////////////////////////
 // Trait implementations - ParseMonthError //
 /////////////////////////////////////////////
@@ -78,6 +254,76 @@ impl std::error::Error for ParseMonthError {
 /////////////////////
 
 impl Month {
+   /// A list of every month in order.
+   pub const ALL : [Self; 12] = [
+      Self::January,
+      Self::February,
+      Self::March,
+      Self::April,
+      Self::May,
+      Self::June,
+      Self::July,
+      Self::August,
+      Self::September,
+      Self::October,
+      Self::November,
+      Self::December,
+   ];
+
+   /// Attempts to parse a month which may be
+   /// misspelled.  The month is first parsed
+   /// normally, then looked up in a table of
+   /// common misspellings, then compared to
+   /// every full month name.  The closest month
+   /// name is used if it is within the maximum
+   /// edit distance and within a third of the
+   /// month name's length, so short words such
+   /// as "Mayor" aren't matched.  Ties between
+   /// months are an error.
+   pub fn from_str_fuzzy(
+      string         : & str,
+      max_distance   : usize,
+   ) -> Result<Self, ParseMonthError> {
+      use std::str::FromStr;
+
+      if let Ok(month) = Self::from_str(string) {
+         return Ok(month);
+      }
+
+      let string = string.trim_end_matches('.').to_lowercase();
+      if let Some((_, month)) = MONTH_MISSPELLINGS.iter().find(|(m, _)| *m == string) {
+         return Ok(*month);
+      }
+      if string.chars().count() < 4 {
+         return Err(ParseMonthError::NoMatchingMonth);
+      }
+
+      // Find the closest full month name
+      let mut closest = None;
+      let mut is_tied = false;
+      for month in Self::ALL {
+         let name       = month.to_string().to_lowercase();
+         let distance   = internal_edit_distance(&string, &name);
+         if distance > max_distance || distance > name.len() / 3 {
+            continue;
+         }
+
+         match closest {
+            Some((_, d)) if d <  distance => (),
+            Some((_, d)) if d == distance => is_tied = true,
+            _                             => {
+               closest = Some((month, distance));
+               is_tied = false;
+            },
+         }
+      }
+
+      return match (closest, is_tied) {
+         (Some((month, _)), false)  => Ok(month),
+         _                          => Err(ParseMonthError::NoMatchingMonth),
+      };
+   }
+
    /// Retrieves the amount of days in the given
    /// month, accounting for leap years.
    pub fn days(&self, is_leap_year : bool) -> usize {
@@ -153,7 +399,7 @@ impl std::str::FromStr for Month {
       if string == "august"      || string == "aug" || string == "aug." {
          return Ok(Month::August);
       }
-      if string == "september"   || string == "sep" || string == "sep." {
+      if string == "september"   || string == "sep" || string == "sep." || string == "sept" || string == "sept." {
          return Ok(Month::September);
       }
       if string == "october"     || string == "oct" || string == "oct." {
@@ -170,6 +416,83 @@ impl std::str::FromStr for Month {
    }
 }
 
+///////////////////////////////////////////////
+// Trait implementations - ParseWeekdayError //
+///////////////////////////////////////////////
+
+impl std::fmt::Display for ParseWeekdayError {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingWeekday => "No matching weekday",
+      });
+   }
+}
+
+impl std::error::Error for ParseWeekdayError {
+}
+
+///////////////////////
+// Methods - Weekday //
+///////////////////////
+
+impl Weekday {
+   /// A list of every weekday in order,
+   /// starting from Monday.
+   pub const ALL : [Self; 7] = [
+      Self::Monday,
+      Self::Tuesday,
+      Self::Wednesday,
+      Self::Thursday,
+      Self::Friday,
+      Self::Saturday,
+      Self::Sunday,
+   ];
+
+   /// Gets the three-letter abbreviation
+   /// of the weekday, such as "Mon".
+   pub fn abbreviation(&self) -> &'static str {
+      return &self.name()[..3];
+   }
+
+   /// Gets the full name of the weekday.
+   fn name(&self) -> &'static str {
+      return match self {
+         Self::Monday      => "Monday",
+         Self::Tuesday     => "Tuesday",
+         Self::Wednesday   => "Wednesday",
+         Self::Thursday    => "Thursday",
+         Self::Friday      => "Friday",
+         Self::Saturday    => "Saturday",
+         Self::Sunday      => "Sunday",
+      };
+   }
+}
+
+/////////////////////////////////////
+// Trait implementations - Weekday //
+/////////////////////////////////////
+
+impl std::fmt::Display for Weekday {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for Weekday {
+   type Err = ParseWeekdayError;
+
+   /// Parses the full name or three-letter
+   /// abbreviation of a weekday, ignoring
+   /// case and a trailing period.
+   fn from_str(string : & str) -> Result<Self, Self::Err> {
+      let string = string.strip_suffix('.').unwrap_or(string);
+
+      return Self::ALL.into_iter()
+         .find(|w| w.name().eq_ignore_ascii_case(string) || w.abbreviation().eq_ignore_ascii_case(string))
+         .ok_or(ParseWeekdayError::NoMatchingWeekday);
+   }
+}
+
 ////////////////////////////////////////////
 // Trait implementations - ParseDateError //
 ////////////////////////////////////////////
@@ -189,6 +512,12 @@ impl std::fmt::Display for ParseDateError {
             => "Invalid day of month",
          Self::InvalidRegexCaptures
             => "Regex does not contain $m, $d, or $y capture groups",
+         Self::InvalidTimeOfDay
+            => "Invalid time of day",
+         Self::InvalidQuarter
+            => "Quarter is not valid",
+         Self::InvalidRange
+            => "Invalid date range",
       });
    }
 }
@@ -196,6 +525,302 @@ impl std::fmt::Display for ParseDateError {
 impl std::error::Error for ParseDateError {
 }
 
+///////////////////////////////////////
+// Trait implementations - Precision //
+///////////////////////////////////////
+
+impl std::fmt::Display for Precision {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::Year     => "year",
+         Self::Quarter  => "quarter",
+         Self::Month    => "month",
+         Self::Day      => "day",
+      });
+   }
+}
+
+////////////////////////
+// Methods - Calendar //
+////////////////////////
+
+impl Calendar {
+   /// A list of every calendar.
+   pub const ALL : [Self; 3] = [
+      Self::Gregorian,
+      Self::Julian,
+      Self::Astronomical,
+   ];
+
+   /// Gets the name of the calendar as
+   /// used on the command line.
+   pub fn name(&self) -> &'static str {
+      return match self {
+         Self::Gregorian      => "gregorian",
+         Self::Julian         => "julian",
+         Self::Astronomical   => "astronomical",
+      };
+   }
+
+   /// Gets whether the calendar's year
+   /// numbering has a year zero.
+   pub fn has_year_zero(&self) -> bool {
+      return *self == Self::Astronomical;
+   }
+
+   /// Converts a year in the calendar's year
+   /// numbering to astronomical year numbering.
+   pub fn astronomical_year(&self, year : isize) -> isize {
+      return match (self.has_year_zero(), year < 0) {
+         (false, true)  => year + 1,
+         _              => year,
+      };
+   }
+
+   /// Converts a year in astronomical year
+   /// numbering to the calendar's year numbering.
+   pub fn from_astronomical_year(&self, year : isize) -> isize {
+      return match (self.has_year_zero(), year <= 0) {
+         (false, true)  => year - 1,
+         _              => year,
+      };
+   }
+
+   /// Converts a year Before Common Era, such
+   /// as "44 BC", to the calendar's year numbering.
+   pub fn year_before_common_era(&self, year : isize) -> isize {
+      return self.from_astronomical_year(1 - year);
+   }
+
+   /// Gets whether a year in the calendar's
+   /// year numbering is a leap year.
+   pub fn is_leap_year(&self, year : isize) -> bool {
+      let year = self.astronomical_year(year);
+
+      return match self {
+         Self::Julian   => year % 4 == 0,
+         _              => year % 4 == 0 && (year % 100 != 0 || year % 400 == 0),
+      };
+   }
+}
+
+//////////////////////////////////////
+// Trait implementations - Calendar //
+//////////////////////////////////////
+
+impl std::default::Default for Calendar {
+   fn default() -> Self {
+      return Self::Gregorian;
+   }
+}
+
+impl std::fmt::Display for Calendar {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for Calendar {
+   type Err = ParseCalendarError;
+
+   fn from_str(string : & str) -> Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|c| c.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseCalendarError::NoMatchingCalendar);
+   }
+}
+
+////////////////////////////////////////////////
+// Trait implementations - ParseCalendarError //
+////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseCalendarError {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingCalendar   => "No matching calendar",
+      });
+   }
+}
+
+impl std::error::Error for ParseCalendarError {
+}
+
+//////////////////////////////////////////
+// Trait implementations - ParseOptions //
+//////////////////////////////////////////
+
+impl std::default::Default for ParseOptions {
+   fn default() -> Self {
+      return Self{
+         calendar       : Calendar::Gregorian,
+         fuzzy_months   : None,
+      };
+   }
+}
+
+////////////////////
+// Methods - Time //
+////////////////////
+
+impl Time {
+   /// Creates a new Time object using a
+   /// 24-hour clock.  A second value of 60
+   /// is allowed to represent leap seconds.
+   /// If the time is not valid, an error
+   /// is returned.
+   pub fn new(
+      hour     : usize,
+      minute   : usize,
+      second   : usize,
+   ) -> Result<Self, ParseDateError> {
+      if hour > 23 || minute > 59 || second > 60 {
+         return Err(ParseDateError::InvalidTimeOfDay);
+      }
+
+      return Ok(Self{
+         hour     : hour,
+         minute   : minute,
+         second   : second,
+      });
+   }
+
+   /// Gets the stored hour.
+   pub fn hour(&self) -> usize {
+      return self.hour;
+   }
+
+   /// Gets the stored minute.
+   pub fn minute(&self) -> usize {
+      return self.minute;
+   }
+
+   /// Gets the stored second.
+   pub fn second(&self) -> usize {
+      return self.second;
+   }
+}
+
+//////////////////////////////////
+// Trait implementations - Time //
+//////////////////////////////////
+
+impl std::fmt::Display for Time {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second);
+   }
+}
+
+////////////////////////////////////////////////
+// Trait implementations - ParseTimeZoneError //
+////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseTimeZoneError {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingTimeZone   => "No matching time zone",
+      });
+   }
+}
+
+impl std::error::Error for ParseTimeZoneError {
+}
+
+////////////////////////
+// Methods - TimeZone //
+////////////////////////
+
+impl TimeZone {
+   /// Creates a new TimeZone from an offset
+   /// from UTC in minutes.  Offsets must be
+   /// less than a day in either direction.
+   pub fn new(
+      offset : isize,
+   ) -> Result<Self, ParseTimeZoneError> {
+      if offset.abs() >= 24 * 60 {
+         return Err(ParseTimeZoneError::NoMatchingTimeZone);
+      }
+
+      return Ok(Self{
+         offset         : offset,
+         abbreviation   : None,
+      });
+   }
+
+   /// Gets the offset from UTC in minutes.
+   pub fn offset(&self) -> isize {
+      return self.offset;
+   }
+
+   /// Gets the time zone's abbreviation,
+   /// if it was created from one.
+   pub fn abbreviation(&self) -> Option<&'static str> {
+      return self.abbreviation;
+   }
+}
+
+//////////////////////////////////////
+// Trait implementations - TimeZone //
+//////////////////////////////////////
+
+impl std::fmt::Display for TimeZone {
+   fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+      if let Some(abbreviation) = self.abbreviation {
+         return write!(stream, "{abbreviation}");
+      }
+
+      return write!(stream,
+         "{}{:02}:{:02}",
+         if self.offset < 0 {"-"} else {"+"},
+         self.offset.abs() / 60,
+         self.offset.abs() % 60,
+      );
+   }
+}
+
+impl std::str::FromStr for TimeZone {
+   type Err = ParseTimeZoneError;
+
+   /// Parses a UTC offset such as "+0900",
+   /// "+09:00", or "-05", or a known time
+   /// zone abbreviation such as "JST".
+   fn from_str(string : & str) -> Result<Self, Self::Err> {
+      use ParseTimeZoneError::*;
+
+      // Look for a known abbreviation
+      if let Some((abbreviation, offset)) = TIME_ZONE_ABBREVIATIONS.iter()
+         .find(|(a, _)| a.eq_ignore_ascii_case(string))
+      {
+         return Ok(Self{
+            offset         : *offset,
+            abbreviation   : Some(abbreviation),
+         });
+      }
+
+      // Parse the sign followed by hours and optional minutes
+      let (sign, digits) = match string.split_at_checked(1) {
+         Some(("+", d)) => (1,  d),
+         Some(("-", d)) => (-1, d),
+         _              => return Err(NoMatchingTimeZone),
+      };
+      let digits = digits.replacen(':', "", 1);
+      if digits.bytes().all(|c| c.is_ascii_digit()) == false {
+         return Err(NoMatchingTimeZone);
+      }
+      let (hours, minutes) = match digits.len() {
+         2  => (&digits[..2], "0"),
+         4  => (&digits[..2], &digits[2..]),
+         _  => return Err(NoMatchingTimeZone),
+      };
+      let hours   : isize = hours  .parse().map_err(|_| NoMatchingTimeZone)?;
+      let minutes : isize = minutes.parse().map_err(|_| NoMatchingTimeZone)?;
+      if minutes > 59 {
+         return Err(NoMatchingTimeZone);
+      }
+
+      return Self::new(sign * (hours * 60 + minutes));
+   }
+}
+
 ////////////////////
 // Methods - Date //
 ////////////////////
@@ -252,21 +877,152 @@ impl Date {
       // Attempt to run the regex parser and get the captures
       let captures = regex.captures(text).ok_or(InvalidFormatting)?;
 
-      // Get captures for month, day, and year
-      let day     = captures.name("d").ok_or(InvalidRegexCaptures)?.as_str();
-      let month   = captures.name("m").ok_or(InvalidRegexCaptures)?.as_str();
+      // Attempt to create a new Date struct from the captures
+      return Self::from_captures(&captures);
+   }
+
+   /// Creates a date from the captures of a
+   /// regular expression match.  The captures
+   /// must contain the named capture group $y
+   /// for the year.  If the named capture groups
+   /// $d and $m for the day and month are also
+   /// matched, a full date is created.  If only
+   /// $m is matched, a month and year date is
+   /// created.  If only $q is matched, a quarter
+   /// and year date is created, where the quarter
+   /// is a number from 1 to 4.  Otherwise, a year
+   /// only date is created.
+   ///
+   /// For full dates, the named capture groups $H, $M,
+   /// and $S for the hour, minute, and second
+   /// are optional.  If $H is matched, the
+   /// date will store a time of day, where a
+   /// missing $M or $S are treated as zero.
+   /// The named capture group $z for the time
+   /// zone is also optional, and is only used
+   /// alongside a time of day.  Time zones which
+   /// fail to parse are ignored.  See TimeZone
+   /// for the supported time zone formats.
+   pub fn from_captures(
+      captures : & regex::Captures,
+   ) -> Result<Self, ParseDateError> {
+      return Self::from_captures_with(captures, &ParseOptions::default()).map(|(date, _)| date);
+   }
+
+   /// Creates a date from the captures of a
+   /// regular expression match, like
+   /// from_captures, using the given options.
+   /// The date is created in the given calendar.
+   /// If fuzzy month matching is enabled and the
+   /// month fails to parse, the month is parsed
+   /// using Month::from_str_fuzzy.  Returns the
+   /// date along with whether the month was
+   /// matched fuzzily.
+   ///
+   /// The month may also be a number from
+   /// 1 to 12, such as in "1995/06/23".
+   /// The day and year may also be spelled out
+   /// in English words, such as "twenty-third"
+   /// and "nineteen ninety-five".
+   ///
+   /// The named capture group $e for the era
+   /// is optional, and may be "BC", "BCE", "AD",
+   /// or "CE", with or without periods.  Years
+   /// with an era must be positive.
+   pub fn from_captures_with(
+      captures : & regex::Captures,
+      options  : & ParseOptions,
+   ) -> Result<(Self, bool), ParseDateError> {
+      use ParseDateError::*;
+
+      let max_distance  = options.fuzzy_months;
+      let calendar      = options.calendar;
+
+      // Parse a month, falling back to fuzzy matching
+      let mut fuzzy = false;
+      let mut parse_month = |text : & str| -> Result<Month, ParseDateError> {
+         if let Ok(month) = text.parse() {
+            return Ok(month);
+         }
+         if let Ok(number) = text.parse::<usize>() {
+            return number.checked_sub(1)
+               .and_then(|i| Month::ALL.get(i).copied())
+               .ok_or(InvalidMonthFormatting);
+         }
+
+         let max_distance = max_distance.ok_or(InvalidMonthFormatting)?;
+         let month = Month::from_str_fuzzy(text, max_distance).map_err(|_| InvalidMonthFormatting)?;
+         fuzzy = true;
+         return Ok(month);
+      };
+
+      // Get captures for month, day, quarter, and year
+      let day     = captures.name("d").map(|c| c.as_str());
+      let month   = captures.name("m").map(|c| c.as_str());
+      let quarter = captures.name("q").map(|c| c.as_str());
       let year    = captures.name("y").ok_or(InvalidRegexCaptures)?.as_str();
 
-      // Parse the found month, day, and year
-      let day     = day    .parse().map_err(|_| InvalidDayFormatting   )?;
-      let month   = month  .parse().map_err(|_| InvalidMonthFormatting )?;
-      let year    = year   .parse().map_err(|_| InvalidYearFormatting  )?;
+      // Parse the found year, applying the optional era
+      let mut year = match year.parse() {
+         Ok(y)    => y,
+         Err(_)   => crate::words::parse_year(year).ok_or(InvalidYearFormatting)? as isize,
+      };
+      if let Some(era) = captures.name("e") {
+         if year <= 0 {
+            return Err(InvalidYearFormatting);
+         }
+
+         let era = era.as_str().replace('.', "").to_uppercase();
+         year = match era.as_str() {
+            "BC" | "BCE"   => calendar.year_before_common_era(year),
+            "AD" | "CE"    => year,
+            _              => return Err(InvalidYearFormatting),
+         };
+      }
+
+      // Create a partial date if there is no day or month
+      let (day, month) = match (day, month, quarter) {
+         (Some(d), Some(m), _      )  => (d, m),
+         (None,    Some(m), _      )  => return Ok((Self::new_month(
+            parse_month(m)?, year,
+         ).with_calendar(calendar)?, fuzzy)),
+         (None,    None,    Some(q))  => return Ok((Self::new_quarter(
+            q.parse().map_err(|_| InvalidQuarter)?, year,
+         )?.with_calendar(calendar)?, false)),
+         (None,    None,    None   )  => return Ok((
+            Self::new_year(year).with_calendar(calendar)?, false,
+         )),
+         (Some(_), None,    _      )  => return Err(InvalidFormatting),
+      };
+
+      // Parse the found month and day
+      let day     = match day.parse() {
+         Ok(d)    => d,
+         Err(_)   => crate::words::parse_number(day).ok_or(InvalidDayFormatting)?,
+      };
+      let month   = parse_month(month)?;
 
       // Attempt to create a new Date struct from the parsed information
-      let date = Self::new(day, month, year)?;
+      let mut date = Self::new_in(day, month, year, calendar)?;
+
+      // Parse the optional time of day
+      if let Some(hour) = captures.name("H") {
+         let parse = |name| match captures.name(name) {
+            Some(c)  => c.as_str().parse().map_err(|_| InvalidTimeOfDay),
+            None     => Ok(0),
+         };
+
+         let hour = hour.as_str().parse().map_err(|_| InvalidTimeOfDay)?;
+         date = date.with_time(Time::new(hour, parse("M")?, parse("S")?)?);
+
+         // Parse the optional time zone
+         if let Some(Ok(zone)) = captures.name("z").map(|z| z.as_str().parse()) {
+            date = date.with_zone(zone);
+         }
+      }
 
       // Return success
-      return Ok(date);
+      return Ok((date, fuzzy));
    }
 
    /// Searches an entire text string for matching
@@ -313,62 +1069,73 @@ impl Date {
       text  : & str,
       regex : & regex::Regex,
    ) -> Result<sorted_vec::SortedVec<Self>, ParseDateError> {
+      let dates = Self::find_all_with(text, regex)?;
+
+      return Ok(sorted_vec::SortedVec::from_unsorted(
+         dates.into_iter().map(|(_, date)| date).collect(),
+      ));
+   }
+
+   /// Searches an entire text string for dates
+   /// matching a given regular expression, returning
+   /// each date along with the byte offset of its
+   /// match within the text.  Dates are returned in
+   /// the order they appear in the text.  See
+   /// Self::from_text_multi_sorted_with for more
+   /// information about the regular expression.
+   pub fn find_all_with(
+      text  : & str,
+      regex : & regex::Regex,
+   ) -> Result<Vec<(usize, Self)>, ParseDateError> {
       use ParseDateError::*;
 
-      let mut dates = sorted_vec::SortedVec::new();
+      let mut dates = Vec::new();
       for cap in regex.captures_iter(text) {
-         // Get string slices to captures
-         let day     = cap.name("d").ok_or(InvalidRegexCaptures)?.as_str();
-         let month   = cap.name("m").ok_or(InvalidRegexCaptures)?.as_str();
-         let year    = cap.name("y").ok_or(InvalidRegexCaptures)?.as_str();
-         
-         // Parse matched text into day, month, year
-         let day = match day.parse() {
-            Ok(d)    => d,
-            Err(_)   => continue,
-         };
-         let month = match month.parse() {
-            Ok(m)    => m,
-            Err(_)   => continue,
-         };
-         let year = match year.parse() {
-            Ok(y)    => y,
-            Err(_)   => continue,
-         };
-
-         // Try to create a new Date
-         let date = match Self::new(day, month, year) {
-            Ok(d)    => d,
-            Err(_)   => continue,
+         // Try to create a new Date, skipping
+         // matches which aren't valid dates
+         let date = match Self::from_captures(&cap) {
+            Ok(d)                      => d,
+            Err(InvalidRegexCaptures)  => return Err(InvalidRegexCaptures),
+            Err(_)                     => continue,
          };
 
          // Add the date to the list
-         dates.push(date);
+         dates.push((cap.get(0).map(|m| m.start()).unwrap_or(0), date));
       }
 
       // Return successfully
       return Ok(dates);
    }
 
-   /// Creates a new Date object.  Negative
+   /// Creates a new Date object using the
+   /// proleptic Gregorian calendar.  Negative
    /// years are considered as dates Before
    /// Common Era (BCE), otherwise known as
    /// Before Christ (BC).  Positive years are
    /// considered as dates during Common Era (CE),
    /// otherwise known as Anno Domini (AD).
    /// If the day of the month is not contained
-   /// within the month, an error is returned.
+   /// within the month, or the year is zero,
+   /// an error is returned.
    pub fn new(
       day_of_month   : usize,
       month          : Month,
       year           : isize,
    ) -> Result<Self, ParseDateError> {
-      let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
-      if day_of_month < 1 || day_of_month > month.days(is_leap_year) {
-         return Err(ParseDateError::InvalidDayOfMonth);
-      } 
+      return Self::new_in(day_of_month, month, year, Calendar::Gregorian);
+   }
 
-      return Ok(unsafe{Self::new_unchecked(day_of_month, month, year)});
+   /// Executes the same as Self::new, but the
+   /// date is created in the given calendar.
+   /// Year zero is only valid in calendars
+   /// with a year zero.
+   pub fn new_in(
+      day_of_month   : usize,
+      month          : Month,
+      year           : isize,
+      calendar       : Calendar,
+   ) -> Result<Self, ParseDateError> {
+      return unsafe{Self::new_unchecked(day_of_month, month, year)}.with_calendar(calendar);
    }
 
    /// Creates a new Date object without
@@ -383,13 +1150,199 @@ impl Date {
       year           : isize,
    ) -> Self {
       return Self{
-         day   : day_of_month,
-         month : month,
-         year  : year,
+         day         : day_of_month,
+         month       : month,
+         year        : year,
+         precision   : Precision::Day,
+         calendar    : Calendar::Gregorian,
+         time        : None,
+         zone        : None,
+      };
+   }
+
+   /// Creates a new Date object where only
+   /// the month and year are known.  The
+   /// date is stored as the first day of
+   /// the month.
+   pub fn new_month(
+      month : Month,
+      year  : isize,
+   ) -> Self {
+      return Self{
+         precision   : Precision::Month,
+         ..unsafe{Self::new_unchecked(1, month, year)}
+      };
+   }
+
+   /// Creates a new Date object where only
+   /// the quarter and year are known.  The
+   /// quarter must be from 1 to 4, otherwise
+   /// an error is returned.  The date is
+   /// stored as the first day of the quarter.
+   pub fn new_quarter(
+      quarter  : usize,
+      year     : isize,
+   ) -> Result<Self, ParseDateError> {
+      use Month::*;
+
+      let month = match quarter {
+         1  => January,
+         2  => April,
+         3  => July,
+         4  => October,
+         _  => return Err(ParseDateError::InvalidQuarter),
+      };
+
+      return Ok(Self{
+         precision   : Precision::Quarter,
+         ..unsafe{Self::new_unchecked(1, month, year)}
+      });
+   }
+
+   /// Creates a new Date object where only
+   /// the year is known.  The date is stored
+   /// as the first day of the year.
+   pub fn new_year(
+      year  : isize,
+   ) -> Self {
+      return Self{
+         precision   : Precision::Year,
+         ..unsafe{Self::new_unchecked(1, Month::January, year)}
+      };
+   }
+
+   /// Creates a new Date object from the
+   /// amount of days since January 1st, 1970
+   /// using the proleptic Gregorian calendar.
+   /// This is the inverse of days_since_epoch.
+   pub fn from_days_since_epoch(
+      days  : i64,
+   ) -> Self {
+      return Self::from_days_since_epoch_in(days, Calendar::Gregorian);
+   }
+
+   /// Executes the same as
+   /// Self::from_days_since_epoch, but the date
+   /// is created in the given calendar.
+   pub fn from_days_since_epoch_in(
+      days     : i64,
+      calendar : Calendar,
+   ) -> Self {
+      let (day, month, year) = match calendar {
+         Calendar::Julian  => {
+            // Julian day number algorithm
+            let c       = days + 2440588 + 32082;
+            let d       = (4 * c + 3).div_euclid(1461);
+            let e       = c - (1461 * d).div_euclid(4);
+            let m       = (5 * e + 2).div_euclid(153);
+            let day     = e - (153 * m + 2).div_euclid(5) + 1;
+            let month   = m + 2 - 12 * (m / 10);
+            let year    = d - 4800 + m / 10;
+
+            (day, month, year)
+         },
+         _                 => {
+            let days          = days + 719468;
+            let era           = days.div_euclid(146097);
+            let day_of_era    = days.rem_euclid(146097);
+            let year_of_era   = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+            let day_of_year   = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+            let month_index   = (5 * day_of_year + 2) / 153;
+            let day           = day_of_year - (153 * month_index + 2) / 5 + 1;
+            let month         = if month_index < 10 {month_index + 2} else {month_index - 10};
+            let year          = year_of_era + era * 400 + if month < 2 {1} else {0};
+
+            (day, month, year)
+         },
+      };
+
+      let year = calendar.from_astronomical_year(year as isize);
+      return Self{
+         calendar : calendar,
+         ..unsafe{Self::new_unchecked(day as usize, Month::ALL[month as usize], year)}
+      };
+   }
+
+   /// Creates a copy of the date with the same
+   /// day, month, and year in the given calendar.
+   /// If the date doesn't exist in the calendar,
+   /// such as February 29th, 1500 in the Gregorian
+   /// calendar, an error is returned.  See
+   /// Self::to_calendar to convert between
+   /// calendars instead.
+   pub fn with_calendar(
+      & self,
+      calendar : Calendar,
+   ) -> Result<Self, ParseDateError> {
+      if self.year == 0 && calendar.has_year_zero() == false {
+         return Err(ParseDateError::InvalidYearFormatting);
+      }
+      if self.day < 1 || self.day > self.month.days(calendar.is_leap_year(self.year)) {
+         return Err(ParseDateError::InvalidDayOfMonth);
+      }
+
+      return Ok(Self{
+         calendar : calendar,
+         ..*self
+      });
+   }
+
+   /// Converts the date to the same day in the
+   /// given calendar.  Partial dates only have
+   /// their year numbering converted.
+   pub fn to_calendar(
+      & self,
+      calendar : Calendar,
+   ) -> Self {
+      if self.precision != Precision::Day {
+         let year = calendar.from_astronomical_year(self.calendar.astronomical_year(self.year));
+         return Self{
+            year     : year,
+            calendar : calendar,
+            ..*self
+         };
+      }
+
+      let date = Self::from_days_since_epoch_in(self.days_since_epoch(), calendar);
+      return Self{
+         day      : date.day,
+         month    : date.month,
+         year     : date.year,
+         calendar : calendar,
+         ..*self
+      };
+   }
+
+   /// Creates a copy of the date which
+   /// stores the given time of day.  The
+   /// time of day is only used for dates
+   /// with a precision of Precision::Day.
+   pub fn with_time(
+      & self,
+      time  : Time,
+   ) -> Self {
+      return Self{
+         time  : Some(time),
+         ..*self
       };
    }
 
-   /// Gets the stored day of the month.
+   /// Creates a copy of the date which
+   /// stores the given time zone.  The
+   /// time zone is only used for dates
+   /// which store a time of day.
+   pub fn with_zone(
+      & self,
+      zone  : TimeZone,
+   ) -> Self {
+      return Self{
+         zone  : Some(zone),
+         ..*self
+      };
+   }
+
+   /// Gets the stored day of the month.  For
+   /// partial dates, this is the first day.
    pub fn day_of_month(&self) -> usize {
       return self.day.clone();
    }
@@ -403,6 +1356,185 @@ impl Date {
    pub fn year(&self) -> isize {
       return self.year.clone();
    }
+
+   /// Gets the quarter of the year the
+   /// date is in, from 1 to 4.
+   pub fn quarter(&self) -> usize {
+      return self.month as usize / 3 + 1;
+   }
+
+   /// Gets how precisely the date is known.
+   pub fn precision(&self) -> Precision {
+      return self.precision;
+   }
+
+   /// Gets the calendar the date is in.
+   pub fn calendar(&self) -> Calendar {
+      return self.calendar;
+   }
+
+   /// Gets the stored time of day, if any.
+   pub fn time(&self) -> Option<Time> {
+      return match self.precision {
+         Precision::Day => self.time,
+         _              => None,
+      };
+   }
+
+   /// Gets the stored time zone, if any.
+   pub fn zone(&self) -> Option<TimeZone> {
+      return match self.precision {
+         Precision::Day => self.zone,
+         _              => None,
+      };
+   }
+
+   /// Gets the amount of days between
+   /// January 1st, 1970 in the proleptic
+   /// Gregorian calendar and the date.
+   /// Dates before then are negative.
+   pub fn days_since_epoch(&self) -> i64 {
+      return internal_saturate(self.internal_days_since_epoch());
+   }
+
+   /// Gets the amount of days since the
+   /// epoch without overflowing, even for
+   /// dates billions of years away.
+   fn internal_days_since_epoch(&self) -> i128 {
+      let month   = self.month as i128 + 1;
+      let day     = self.day as i128;
+      let year    = self.calendar.astronomical_year(self.year) as i128;
+
+      // Julian day number algorithm
+      if self.calendar == Calendar::Julian {
+         let a = (14 - month) / 12;
+         let y = year + 4800 - a;
+         let m = month + 12 * a - 3;
+
+         return day + (153 * m + 2) / 5 + 365 * y + y.div_euclid(4) - 32083 - 2440588;
+      }
+
+      let year    = year - if month <= 2 {1} else {0};
+
+      let era           = year.div_euclid(400);
+      let year_of_era   = year.rem_euclid(400);
+      let day_of_year   = (153 * (month + if month > 2 {-3} else {9}) + 2) / 5 + day - 1;
+      let day_of_era    = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+      return era * 146097 + day_of_era - 719468;
+   }
+
+   /// Gets the day of the week the date
+   /// falls on.  Dates only known to the
+   /// month, quarter, or year use their
+   /// first day.
+   pub fn day_of_week(&self) -> Weekday {
+      // January 1st, 1970 was a Thursday
+      return Weekday::ALL[(self.days_since_epoch() + 3).rem_euclid(7) as usize];
+   }
+
+   /// Gets the amount of seconds between
+   /// midnight UTC on January 1st, 1970 and
+   /// the date.  Dates without a time of day
+   /// are treated as midnight, and times
+   /// without a time zone are treated as UTC.
+   /// Partial dates are treated as midnight
+   /// on their first day.  Dates too far away
+   /// to be represented are clamped.
+   pub fn seconds_since_epoch(&self) -> i64 {
+      return internal_saturate(self.internal_seconds_since_epoch());
+   }
+
+   /// Gets the amount of seconds since the
+   /// epoch without overflowing, even for
+   /// dates billions of years away.
+   fn internal_seconds_since_epoch(&self) -> i128 {
+      let mut seconds = self.internal_days_since_epoch() * 24 * 60 * 60;
+
+      if let Some(time) = self.time() {
+         seconds += (time.hour * 60 * 60 + time.minute * 60 + time.second) as i128;
+         seconds -= self.zone().map(|z| z.offset as i128 * 60).unwrap_or(0);
+      }
+
+      return seconds;
+   }
+
+   /// Formats the date as ISO 8601 text in the
+   /// proleptic Gregorian calendar with year zero,
+   /// such as "1995-06-23T14:02:11+09:00".  Partial
+   /// dates only show what is known, such as
+   /// "1995-06", "1995-Q3", or "1995".
+   pub fn to_iso8601(&self) -> String {
+      let date = self.to_calendar(Calendar::Astronomical);
+      let year = match date.year {
+         0..=9999 => format!("{:04}", date.year),
+         _        => format!("{:+05}", date.year),
+      };
+
+      let mut text = match self.precision {
+         Precision::Year      => year,
+         Precision::Quarter   => format!("{year}-Q{}", date.quarter()),
+         Precision::Month     => format!("{year}-{:02}", date.month as usize + 1),
+         Precision::Day       => format!("{year}-{:02}-{:02}", date.month as usize + 1, date.day),
+      };
+
+      if let Some(time) = self.time() {
+         text.push_str(&format!("T{:02}:{:02}:{:02}", time.hour(), time.minute(), time.second()));
+         if let Some(zone) = self.zone() {
+            let offset = zone.offset();
+            text.push_str(&format!(
+               "{}{:02}:{:02}",
+               if offset < 0 {"-"} else {"+"},
+               offset.abs() / 60,
+               offset.abs() % 60,
+            ));
+         }
+      }
+
+      return text;
+   }
+
+   /// Parses a date written as ISO 8601 text
+   /// by Self::to_iso8601, such as "1995-06-23",
+   /// "1995-Q3", or "1995-06-23T14:02:11+09:00".
+   /// Years are astronomical, and the date is
+   /// created in the proleptic Gregorian calendar.
+   /// The original calendar and time zone
+   /// abbreviation aren't stored in the text, so
+   /// they're lost in the round trip.
+   pub fn from_iso8601(
+      text  : & str,
+   ) -> Result<Self, ParseDateError> {
+      use regex::Regex;
+
+      lazy_static::lazy_static!{
+         static ref RX_ISO8601 : Regex = Regex::new(r"(?x)
+            ^                                      # Start of string
+            (?P<y>[+-]?\d{4,})                     # Year
+            (?:
+               -Q(?P<q>\d)                         # Quarter
+            |
+               -(?P<m>\d{2})                       # Month
+               (?:-(?P<d>\d{2})                    # Day
+                  (?:T(?P<H>\d{2})                 # Hour
+                     :(?P<M>\d{2}):(?P<S>\d{2})    # Minute and second
+                     (?P<z>[+-]\d{2}:\d{2})?       # Time zone
+                  )?
+               )?
+            )?
+            $                                      # End of string
+         ").unwrap();
+      }
+
+      let options = ParseOptions{
+         calendar : Calendar::Astronomical,
+         ..ParseOptions::default()
+      };
+      let captures = RX_ISO8601.captures(text).ok_or(ParseDateError::InvalidFormatting)?;
+      let (date, _) = Self::from_captures_with(&captures, &options)?;
+
+      return Ok(date.to_calendar(Calendar::Gregorian));
+   }
 }
 
 //////////////////////////////////
@@ -411,6 +1543,21 @@ impl Date {
 
 impl std::cmp::PartialOrd for Date {
    fn partial_cmp(&self, other : &Self) -> Option<std::cmp::Ordering> {
+      // Compare the moments in time first, so times
+      // in different time zones are ordered correctly
+      let instant       = self  .internal_seconds_since_epoch();
+      let instant_other = other .internal_seconds_since_epoch();
+      if instant != instant_other {
+         return instant.partial_cmp(&instant_other);
+      }
+
+      // Partial dates come before full dates
+      // starting at the same moment in time
+      if self.precision != other.precision {
+         return self.precision.partial_cmp(&other.precision);
+      }
+
+      // Break ties between equal moments in time
       if self.year   != other.year {
          return self.year  .partial_cmp(&other.year);
       };
@@ -420,6 +1567,15 @@ impl std::cmp::PartialOrd for Date {
       if self.day    != other.day {
          return self.day   .partial_cmp(&other.day );
       }
+      if self.time   != other.time {
+         return self.time  .partial_cmp(&other.time);
+      }
+      if self.zone   != other.zone {
+         return self.zone  .partial_cmp(&other.zone);
+      }
+      if self.calendar != other.calendar {
+         return self.calendar.partial_cmp(&other.calendar);
+      }
 
       return Some(std::cmp::Ordering::Equal);
    }
@@ -435,8 +1591,29 @@ impl std::cmp::Ord for Date {
 
 impl std::fmt::Display for Date {
    fn fmt(&self, stream : & mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-      return write!(stream,
-         "{} {}{}, {}{}",
+      let year = match (self.calendar.has_year_zero(), self.year < 0) {
+         (false, true)  => format!("{} BCE", -self.year),
+         _              => format!("{}", self.year),
+      };
+      let calendar = match self.calendar {
+         Calendar::Julian  => " (Julian)",
+         _                 => "",
+      };
+
+      // Partial dates only show what is known
+      match self.precision {
+         Precision::Year
+            => return write!(stream, "{year}{calendar}"),
+         Precision::Quarter
+            => return write!(stream, "Q{} {year}{calendar}", self.quarter()),
+         Precision::Month
+            => return write!(stream, "{} {year}{calendar}", self.month),
+         Precision::Day
+            => (),
+      }
+
+      write!(stream,
+         "{} {}{}, {}",
          self.month,
          self.day,
          if self.day >= 10 && self.day <= 19 {
@@ -449,13 +1626,37 @@ impl std::fmt::Display for Date {
                _  => "th",
             }
          },
-         self.year.abs(),
-         if self.year >= 0 {
-            ""
-         } else {
-            " BCE"
-         },
-      );
+         year,
+      )?;
+
+      if let Some(time) = self.time {
+         write!(stream, " {time}")?;
+      }
+      if let (Some(_), Some(zone)) = (self.time, self.zone) {
+         write!(stream, " {zone}")?;
+      }
+      write!(stream, "{calendar}")?;
+
+      return Ok(());
+   }
+}
+
+impl serde::Serialize for Date {
+   /// Serializes the date as ISO 8601 text.
+   /// See Self::to_iso8601.
+   fn serialize<S>(&self, serializer : S) -> Result<S::Ok, S::Error>
+   where S: serde::Serializer {
+      return serializer.serialize_str(&self.to_iso8601());
+   }
+}
+
+impl<'de> serde::Deserialize<'de> for Date {
+   /// Deserializes the date from ISO 8601
+   /// text.  See Self::from_iso8601.
+   fn deserialize<D>(deserializer : D) -> Result<Self, D::Error>
+   where D: serde::Deserializer<'de> {
+      let text = String::deserialize(deserializer)?;
+      return Self::from_iso8601(&text).map_err(serde::de::Error::custom);
    }
 }
 
@@ -467,3 +1668,52 @@ impl std::str::FromStr for Date {
    }
 }
 
+///////////////
+// Functions //
+///////////////
+
+/// Gets the edit distance between two
+/// strings, where inserting, deleting, or
+/// replacing a character or swapping two
+/// neighbouring characters each count as
+/// a single edit.
+fn internal_edit_distance(
+   left  : & str,
+   right : & str,
+) -> usize {
+   let left    = left .chars().collect::<Vec<_>>();
+   let right   = right.chars().collect::<Vec<_>>();
+
+   // distance[i][j] is the distance between left[..i] and right[..j]
+   let mut distance = vec![vec![0; right.len() + 1]; left.len() + 1];
+   for (i, row) in distance.iter_mut().enumerate() {
+      row[0] = i;
+   }
+   for (j, cell) in distance[0].iter_mut().enumerate() {
+      *cell = j;
+   }
+
+   for i in 1..=left.len() {
+      for j in 1..=right.len() {
+         let cost = if left[i - 1] == right[j - 1] {0} else {1};
+
+         distance[i][j] = (distance[i - 1][j] + 1)
+            .min(distance[i][j - 1] + 1)
+            .min(distance[i - 1][j - 1] + cost);
+
+         if i > 1 && j > 1 && left[i - 1] == right[j - 2] && left[i - 2] == right[j - 1] {
+            distance[i][j] = distance[i][j].min(distance[i - 2][j - 2] + 1);
+         }
+      }
+   }
+
+   return distance[left.len()][right.len()];
+}
+
+/// Clamps a wide integer into the range
+/// of an i64.
+fn internal_saturate(
+   value : i128,
+) -> i64 {
+   return value.clamp(i64::MIN as i128, i64::MAX as i128) as i64;
+}
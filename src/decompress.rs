@@ -0,0 +1,192 @@
+//! Utilities for transparently decompressing files.
+//!
+//! Files compressed as a single stream with gzip,
+//! bzip2, or xz are detected by their magic bytes.
+//! The decompressed data is limited to a maximum
+//! size to guard against decompression bombs.
+//! Files which only start with the magic bytes by
+//! chance, such as text starting with "BZh", are
+//! searched as they are unless their extension
+//! also says they're compressed.
+
+///////////////
+// Constants //
+///////////////
+
+/// The default maximum size of decompressed
+/// data, in bytes.
+pub const DEFAULT_SIZE_LIMIT : u64 = 256 * 1024 * 1024;
+
+const MAGIC_GZIP     : &[u8] = b"\x1F\x8B";
+const MAGIC_BZIP2    : &[u8] = b"BZh";
+const MAGIC_XZ       : &[u8] = b"\xFD7zXZ\x00";
+
+const GZIP_EXTENSIONS   : [&str; 2] = ["gz",  "tgz"];
+const BZIP2_EXTENSIONS  : [&str; 3] = ["bz2", "tbz", "tbz2"];
+const XZ_EXTENSIONS     : [&str; 2] = ["xz",  "txz"];
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// A compression format which can be
+/// decompressed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression {
+   Gzip,
+   Bzip2,
+   Xz,
+}
+
+///////////////////////////
+// Methods - Compression //
+///////////////////////////
+
+impl Compression {
+   /// Detects the compression format of
+   /// data from its first few bytes.  If
+   /// the data is not compressed with a
+   /// known format, None is returned.
+   pub fn detect(
+      header : & [u8],
+   ) -> Option<Self> {
+      if header.starts_with(MAGIC_GZIP) {
+         return Some(Self::Gzip);
+      }
+      if header.starts_with(MAGIC_BZIP2) {
+         return Some(Self::Bzip2);
+      }
+      if header.starts_with(MAGIC_XZ) {
+         return Some(Self::Xz);
+      }
+
+      return None;
+   }
+
+   /// Chooses the compression format of a
+   /// file from its extension, matched
+   /// case-insensitively.  If the extension
+   /// isn't a known compressed format, None
+   /// is returned.
+   pub fn from_path(
+      path  : & std::path::Path,
+   ) -> Option<Self> {
+      let extension = path.extension()?.to_str()?.to_lowercase();
+      let extension = extension.as_str();
+      if GZIP_EXTENSIONS.contains(&extension) {
+         return Some(Self::Gzip);
+      }
+      if BZIP2_EXTENSIONS.contains(&extension) {
+         return Some(Self::Bzip2);
+      }
+      if XZ_EXTENSIONS.contains(&extension) {
+         return Some(Self::Xz);
+      }
+
+      return None;
+   }
+
+   /// Decompresses data with the compression
+   /// format.  If the decompressed data is
+   /// larger than the size limit in bytes,
+   /// an error is returned.  Truncated or
+   /// corrupt data is reported as invalid
+   /// data.
+   pub fn decompress(
+      & self,
+      data        : & [u8],
+      size_limit  : u64,
+   ) -> crate::collect::Result<Vec<u8>> {
+      use crate::collect::CollectDateError;
+
+      let decompressed = match self {
+         Self::Gzip
+            => read_limited(flate2::read::MultiGzDecoder::new(data), size_limit),
+         Self::Bzip2
+            => read_limited(bzip2::read::MultiBzDecoder::new(data), size_limit),
+         Self::Xz
+            => read_limited(xz2::read::XzDecoder::new_multi_decoder(data), size_limit),
+      };
+
+      // The data is already in memory, so any
+      // read error comes from the decoder
+      return decompressed.map_err(|e| match e {
+         CollectDateError::DataTooLarge   => CollectDateError::DataTooLarge,
+         _                                => CollectDateError::InvalidData,
+      });
+   }
+}
+
+/////////////////////////////////////////
+// Trait implementations - Compression //
+/////////////////////////////////////////
+
+impl std::fmt::Display for Compression {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::Gzip  => "gzip",
+         Self::Bzip2 => "bzip2",
+         Self::Xz    => "xz",
+      });
+   }
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Decompresses data if it is compressed
+/// with a known format, otherwise None is
+/// returned.  See Compression::decompress
+/// for more information.
+pub fn decompress(
+   data        : & [u8],
+   size_limit  : u64,
+) -> crate::collect::Result<Option<Vec<u8>>> {
+   return match Compression::detect(data) {
+      Some(c)  => Ok(Some(c.decompress(data, size_limit)?)),
+      None     => Ok(None),
+   };
+}
+
+/// Executes the same as decompress, but if
+/// data which only looked compressed by its
+/// magic bytes can't be decompressed, None is
+/// returned so it's searched as it is.  Data
+/// is only reported as invalid if the file's
+/// extension also says it's compressed.
+pub fn decompress_file(
+   path        : & std::path::Path,
+   data        : & [u8],
+   size_limit  : u64,
+) -> crate::collect::Result<Option<Vec<u8>>> {
+   use crate::collect::CollectDateError;
+
+   return match decompress(data, size_limit) {
+      Err(CollectDateError::InvalidData) if Compression::from_path(path).is_none()
+               => Ok(None),
+      result   => result,
+   };
+}
+
+/// Reads a stream to its end, returning
+/// an error if more than the size limit
+/// in bytes is read.
+pub fn read_limited<R>(
+   reader      : R,
+   size_limit  : u64,
+) -> crate::collect::Result<Vec<u8>>
+where R: std::io::Read {
+   use std::io::Read;
+
+   let mut buffer = Vec::new();
+   reader.take(size_limit.saturating_add(1)).read_to_end(&mut buffer)?;
+   if buffer.len() as u64 > size_limit {
+      return Err(crate::collect::CollectDateError::DataTooLarge);
+   }
+
+   return Ok(buffer);
+}
@@ -0,0 +1,400 @@
+//! Utilities for comparing two scans of the
+//! same tree.
+//!
+//! A ScanDiff lists the files only found in one
+//! of the scans, the files whose dates changed
+//! between the scans, and how the oldest, newest,
+//! and median files of the tree shifted.  Files
+//! are matched by path, and their dates are
+//! compared by value, so a date which only moved
+//! within its file isn't a change.  See
+//! crate::scan for saving scans.
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// An error type representing an error
+/// relating to a scan diff.
+#[derive(Copy, Clone, Debug)]
+pub enum DiffError {
+   /// Diffs can't be written in the
+   /// requested output format.
+   UnsupportedFormat,
+}
+
+/// A file found in both scans whose
+/// dates changed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChangedFile {
+   path     : std::path::PathBuf,
+   added    : Vec<crate::date::Date>,
+   removed  : Vec<crate::date::Date>,
+}
+
+/// How one of the report's files, such as
+/// the oldest file, changed between scans.
+/// Each side stores the file's path and its
+/// first date, or None if that scan has no
+/// dates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileShift {
+   before   : Option<(std::path::PathBuf, crate::date::Date)>,
+   after    : Option<(std::path::PathBuf, crate::date::Date)>,
+}
+
+/// The differences between two scans.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanDiff {
+   added    : Vec<std::path::PathBuf>,
+   removed  : Vec<std::path::PathBuf>,
+   changed  : Vec<ChangedFile>,
+   oldest   : FileShift,
+   newest   : FileShift,
+   median   : FileShift,
+}
+
+///////////////////////////////////////
+// Trait implementations - DiffError //
+///////////////////////////////////////
+
+impl std::fmt::Display for DiffError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::UnsupportedFormat => "Diffs can only be written as text or json",
+      });
+   }
+}
+
+impl std::error::Error for DiffError {
+}
+
+///////////////////////////
+// Methods - ChangedFile //
+///////////////////////////
+
+impl ChangedFile {
+   /// Gets the path of the file.
+   pub fn path<'l>(
+      &'l self,
+   ) -> &'l std::path::Path {
+      return &self.path;
+   }
+
+   /// Gets the dates only found in the
+   /// newer scan, from oldest to newest.
+   pub fn added<'l>(
+      &'l self,
+   ) -> &'l [crate::date::Date] {
+      return &self.added;
+   }
+
+   /// Gets the dates only found in the
+   /// older scan, from oldest to newest.
+   pub fn removed<'l>(
+      &'l self,
+   ) -> &'l [crate::date::Date] {
+      return &self.removed;
+   }
+}
+
+/////////////////////////
+// Methods - FileShift //
+/////////////////////////
+
+impl FileShift {
+   /// Gets the file and its first date
+   /// in the older scan.
+   pub fn before<'l>(
+      &'l self,
+   ) -> Option<(&'l std::path::Path, crate::date::Date)> {
+      return self.before.as_ref().map(|(path, date)| (path.as_path(), *date));
+   }
+
+   /// Gets the file and its first date
+   /// in the newer scan.
+   pub fn after<'l>(
+      &'l self,
+   ) -> Option<(&'l std::path::Path, crate::date::Date)> {
+      return self.after.as_ref().map(|(path, date)| (path.as_path(), *date));
+   }
+
+   /// Gets the amount of days the first
+   /// date moved by, where a negative amount
+   /// is earlier, or None if either scan
+   /// has no dates.
+   pub fn days(
+      & self,
+   ) -> Option<i64> {
+      let (_, before)   = self.before()?;
+      let (_, after)    = self.after()?;
+      return Some(after.days_since_epoch() - before.days_since_epoch());
+   }
+}
+
+////////////////////////
+// Methods - ScanDiff //
+////////////////////////
+
+impl ScanDiff {
+   /// Compares an older scan with a
+   /// newer scan of the same tree.
+   pub fn new(
+      before   : & crate::collect::FileAggregateDateList,
+      after    : & crate::collect::FileAggregateDateList,
+   ) -> Self {
+      use std::collections::BTreeMap;
+
+      let index = |files : &crate::collect::FileAggregateDateList| files.iter()
+         .map(|fd| (fd.path().to_path_buf(), fd.dates().iter().map(|d| d.date()).collect::<Vec<_>>()))
+         .collect::<BTreeMap<_, _>>();
+      let before_files  = index(before);
+      let after_files   = index(after);
+
+      let added = after_files.keys()
+         .filter(|path| before_files.contains_key(*path) == false)
+         .cloned()
+         .collect();
+      let removed = before_files.keys()
+         .filter(|path| after_files.contains_key(*path) == false)
+         .cloned()
+         .collect();
+
+      let mut changed = Vec::new();
+      for (path, before_dates) in before_files.iter() {
+         let Some(after_dates) = after_files.get(path) else {
+            continue;
+         };
+
+         let added   = internal_missing_dates(after_dates, before_dates);
+         let removed = internal_missing_dates(before_dates, after_dates);
+         if added.is_empty() == false || removed.is_empty() == false {
+            changed.push(ChangedFile{
+               path     : path.clone(),
+               added    : added,
+               removed  : removed,
+            });
+         }
+      }
+
+      // The oldest, newest, and median files of each scan
+      let summary = |files : &crate::collect::FileAggregateDateList| match files.create_report() {
+         Ok(report)  => [report.oldest(), report.newest(), report.median()].map(internal_first_date),
+         Err(_)      => [None, None, None],
+      };
+      let (before_summary, after_summary) = (summary(before), summary(after));
+      let [oldest, newest, median] = std::array::from_fn(|i| FileShift{
+         before   : before_summary[i].clone(),
+         after    : after_summary [i].clone(),
+      });
+
+      return Self{
+         added    : added,
+         removed  : removed,
+         changed  : changed,
+         oldest   : oldest,
+         newest   : newest,
+         median   : median,
+      };
+   }
+
+   /// Gets the files only found in the
+   /// newer scan, sorted by path.
+   pub fn added<'l>(
+      &'l self,
+   ) -> &'l [std::path::PathBuf] {
+      return &self.added;
+   }
+
+   /// Gets the files only found in the
+   /// older scan, sorted by path.
+   pub fn removed<'l>(
+      &'l self,
+   ) -> &'l [std::path::PathBuf] {
+      return &self.removed;
+   }
+
+   /// Gets the files found in both scans
+   /// whose dates changed, sorted by path.
+   pub fn changed<'l>(
+      &'l self,
+   ) -> &'l [ChangedFile] {
+      return &self.changed;
+   }
+
+   /// Gets how the oldest file changed.
+   pub fn oldest<'l>(
+      &'l self,
+   ) -> &'l FileShift {
+      return &self.oldest;
+   }
+
+   /// Gets how the newest file changed.
+   pub fn newest<'l>(
+      &'l self,
+   ) -> &'l FileShift {
+      return &self.newest;
+   }
+
+   /// Gets how the median file changed.
+   pub fn median<'l>(
+      &'l self,
+   ) -> &'l FileShift {
+      return &self.median;
+   }
+
+   /// Gets whether the scans have no
+   /// differences.
+   pub fn is_empty(
+      & self,
+   ) -> bool {
+      return self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty();
+   }
+
+   /// Writes the diff as machine-readable
+   /// JSON.  Dates are written as ISO 8601
+   /// text.
+   pub fn to_json(
+      & self,
+   ) -> String {
+      use serde_json::json;
+
+      let path = |path : & std::path::Path| path.to_string_lossy().into_owned();
+      let side = |side : Option<(& std::path::Path, crate::date::Date)>| side.map(|(file, date)| json!({
+         "path"   : path(file),
+         "date"   : date,
+      }));
+      let shift = |shift : & FileShift| json!({
+         "before" : side(shift.before()),
+         "after"  : side(shift.after()),
+         "days"   : shift.days(),
+      });
+
+      let changed = self.changed.iter().map(|file| json!({
+         "path"      : path(file.path()),
+         "added"     : file.added(),
+         "removed"   : file.removed(),
+      })).collect::<Vec<_>>();
+
+      let diff = json!({
+         "added"     : self.added.iter().map(|p| path(p)).collect::<Vec<_>>(),
+         "removed"   : self.removed.iter().map(|p| path(p)).collect::<Vec<_>>(),
+         "changed"   : changed,
+         "oldest"    : shift(&self.oldest),
+         "newest"    : shift(&self.newest),
+         "median"    : shift(&self.median),
+      });
+
+      return serde_json::to_string_pretty(&diff).unwrap(); // Never fails for a JSON value
+   }
+
+   /// Writes the diff in the given format,
+   /// which must be text or JSON.
+   pub fn to_format(
+      & self,
+      format      : crate::report::OutputFormat,
+   ) -> Result<String, DiffError> {
+      use crate::report::OutputFormat;
+
+      return match format {
+         OutputFormat::Text   => Ok(self.to_string()),
+         OutputFormat::Json   => Ok(self.to_json()),
+         _                    => Err(DiffError::UnsupportedFormat),
+      };
+   }
+}
+
+//////////////////////////////////////
+// Trait implementations - ScanDiff //
+//////////////////////////////////////
+
+impl std::fmt::Display for ScanDiff {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      write!(stream, "--------- Diff Summary ----------\n\n")?;
+      write!(stream, "Files added: {}\n",    self.added.len())?;
+      write!(stream, "Files removed: {}\n",  self.removed.len())?;
+      write!(stream, "Files changed: {}\n",  self.changed.len())?;
+
+      for (name, shift) in [("Oldest", &self.oldest), ("Newest", &self.newest), ("Median", &self.median)] {
+         write!(stream, "\n{name} file:\n")?;
+         for (label, side) in [("Before:", shift.before()), ("After:", shift.after())] {
+            match side {
+               Some((path, date))   => write!(stream, "   {label:<7} {} ({date})\n", path.to_str().unwrap_or("???"))?,
+               None                 => write!(stream, "   {label:<7} no dates\n")?,
+            }
+         }
+         match shift.days() {
+            Some(0)                 => write!(stream, "   Unchanged\n")?,
+            Some(days) if days < 0  => write!(stream, "   Moved {} day{} earlier\n", -days, if days == -1 {""} else {"s"})?,
+            Some(days)              => write!(stream, "   Moved {days} day{} later\n", if days == 1 {""} else {"s"})?,
+            None                    => (),
+         }
+      }
+
+      if self.added.is_empty() == false {
+         write!(stream, "\n---------- Added Files ----------\n\n")?;
+         for path in self.added.iter() {
+            write!(stream, "{}\n", path.to_str().unwrap_or("???"))?;
+         }
+      }
+
+      if self.removed.is_empty() == false {
+         write!(stream, "\n--------- Removed Files ---------\n\n")?;
+         for path in self.removed.iter() {
+            write!(stream, "{}\n", path.to_str().unwrap_or("???"))?;
+         }
+      }
+
+      if self.changed.is_empty() == false {
+         write!(stream, "\n--------- Changed Files ---------\n")?;
+         for file in self.changed.iter() {
+            write!(stream, "\n{}\n", file.path().to_str().unwrap_or("???"))?;
+            for date in file.added() {
+               write!(stream, "   + {date}\n")?;
+            }
+            for date in file.removed() {
+               write!(stream, "   - {date}\n")?;
+            }
+         }
+      }
+
+      return Ok(());
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Gets the dates in a list which are
+/// missing from another list, counting
+/// repeated dates separately.
+fn internal_missing_dates(
+   dates : & [crate::date::Date],
+   other : & [crate::date::Date],
+) -> Vec<crate::date::Date> {
+   let mut other     = other.to_vec();
+   let mut missing   = Vec::new();
+   for date in dates {
+      match other.iter().position(|d| d == date) {
+         Some(index) => {other.remove(index);},
+         None        => missing.push(*date),
+      }
+   }
+
+   return missing;
+}
+
+/// Gets a file's path and its first date,
+/// if it has any dates.
+fn internal_first_date(
+   file  : & crate::collect::FileDateList,
+) -> Option<(std::path::PathBuf, crate::date::Date)> {
+   return file.dates().first().map(|date| (file.path().to_path_buf(), date.date()));
+}
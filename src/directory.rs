@@ -0,0 +1,187 @@
+//! Utilities for rolling up the dates of
+//! files into per-directory statistics.
+//!
+//! Every directory from the deepest common
+//! ancestor of the searched files down to a
+//! configurable depth gets statistics gathered
+//! from every file below it, so a codebase can
+//! be compared directory by directory, such as
+//! seeing that "src/engine/" predates "src/game/".
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// Statistics about the dates of every
+/// file within a directory, including
+/// files within subdirectories.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectoryStats {
+   path        : std::path::PathBuf,
+   depth       : usize,
+   file_count  : usize,
+   date_count  : usize,
+   earliest    : crate::date::Date,
+   latest      : crate::date::Date,
+   median      : crate::date::Date,
+}
+
+//////////////////////////////
+// Methods - DirectoryStats //
+//////////////////////////////
+
+impl DirectoryStats {
+   /// Gathers statistics for every directory
+   /// from the deepest common ancestor of the
+   /// files, at depth 0, down to the given
+   /// depth.  Files deeper than the depth are
+   /// counted in their ancestor at that depth.
+   /// Files without dates are skipped, and the
+   /// statistics are sorted by path, which
+   /// lists each directory before its
+   /// subdirectories.
+   pub fn from_files(
+      file_data   : & crate::collect::FileAggregateDateList,
+      max_depth   : usize,
+   ) -> Vec<Self> {
+      use std::path::{Path, PathBuf};
+
+      let files = file_data.iter()
+         .filter(|fd| fd.dates().is_empty() == false)
+         .collect::<Vec<_>>();
+      let parent = |path : &'_ Path| path.parent().unwrap_or(Path::new("")).to_path_buf();
+
+      // Find the deepest common ancestor
+      let mut root = match files.first() {
+         Some(fd) => parent(fd.path()),
+         None     => return Vec::new(),
+      };
+      for file in files.iter() {
+         while file.path().starts_with(&root) == false {
+            root = parent(&root);
+         }
+      }
+
+      // Gather every date below each directory
+      let mut directories = std::collections::BTreeMap::<PathBuf, (usize, usize, Vec<crate::date::Date>)>::new();
+      for file in files.iter() {
+         let relative = parent(file.path());
+         let relative = relative.strip_prefix(&root).unwrap_or(Path::new(""));
+
+         let mut path = root.clone();
+         for depth in 0..=max_depth {
+            let entry = directories.entry(path.clone()).or_insert((depth, 0, Vec::new()));
+            entry.1 += 1;
+            entry.2.extend(file.dates().iter().map(|date| date.date()));
+
+            match relative.components().nth(depth) {
+               Some(component)   => path.push(component),
+               None              => break,
+            }
+         }
+      }
+
+      return directories.into_iter()
+         .map(|(path, (depth, file_count, mut dates))| {
+            dates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+            return Self{
+               path        : path,
+               depth       : depth,
+               file_count  : file_count,
+               date_count  : dates.len(),
+               earliest    : dates[0],              // Never empty
+               latest      : dates[dates.len() - 1],
+               median      : dates[dates.len() / 2],
+            };
+         })
+         .collect();
+   }
+
+   /// Gets the path to the directory.
+   pub fn path<'l>(
+      &'l self,
+   ) -> &'l std::path::Path {
+      return &self.path;
+   }
+
+   /// Gets how many directories deep the
+   /// directory is below the common ancestor
+   /// of the files.
+   pub fn depth(
+      & self,
+   ) -> usize {
+      return self.depth;
+   }
+
+   /// Gets the amount of files with dates
+   /// within the directory.
+   pub fn file_count(
+      & self,
+   ) -> usize {
+      return self.file_count;
+   }
+
+   /// Gets the amount of dates found within
+   /// the directory.
+   pub fn date_count(
+      & self,
+   ) -> usize {
+      return self.date_count;
+   }
+
+   /// Gets the oldest date within the
+   /// directory.
+   pub fn earliest(
+      & self,
+   ) -> crate::date::Date {
+      return self.earliest;
+   }
+
+   /// Gets the newest date within the
+   /// directory.
+   pub fn latest(
+      & self,
+   ) -> crate::date::Date {
+      return self.latest;
+   }
+
+   /// Gets the median date within the
+   /// directory.
+   pub fn median(
+      & self,
+   ) -> crate::date::Date {
+      return self.median;
+   }
+}
+
+////////////////////////////////////////////
+// Trait implementations - DirectoryStats //
+////////////////////////////////////////////
+
+impl std::fmt::Display for DirectoryStats {
+   /// Formats the statistics as a single line
+   /// of a tree, indented by the depth and
+   /// showing only the directory's name below
+   /// the common ancestor.
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      let name = match self.depth {
+         0 => self.path.to_str().filter(|p| p.is_empty() == false).unwrap_or("."),
+         _ => self.path.file_name().and_then(|n| n.to_str()).unwrap_or("???"),
+      };
+
+      return write!(stream,
+         "{}{name}/ ({} file{}, {} to {}, median {})",
+         "   ".repeat(self.depth),
+         self.file_count,
+         if self.file_count == 1 {""} else {"s"},
+         self.earliest,
+         self.latest,
+         self.median,
+      );
+   }
+}
+
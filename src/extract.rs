@@ -0,0 +1,891 @@
+//! Utilities for extracting dates from text.
+//!
+//! Dates are extracted using regular expressions
+//! following the capture group conventions described
+//! in crate::Date::from_captures.  A DateExtractor
+//! runs several regular expressions over the same
+//! text, such as the built-in formats, and resolves
+//! overlapping matches so each piece of text is
+//! only used once.
+//!
+//! Date ranges and lists are described using extra
+//! capture groups.  If $d2 is matched, every date
+//! from the start date up to the day $d2, month $m2,
+//! and year $y2 is found.  A missing $m2 is the
+//! start date's month, and a missing $y2 is the
+//! start date's year, or the next year if $m2 comes
+//! before the start month.  If $l is matched,
+//! every number inside of it is used as another day
+//! of the start date's month and year.
+//!
+//! The author of a date, such as in "95/06/23 by
+//! Nishiwaki", is captured with the capture group $a.
+//! A DateExtractor also matches its author patterns
+//! against the text directly after each date, such
+//! as "June 23, 1995 - tk: fixed camera".
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// Trait for types which can find every
+/// date within a text string.
+pub trait Extract {
+   /// Finds every date within a text string,
+   /// returning each date along with the byte
+   /// offset where it starts.  Dates are
+   /// returned in the order they appear in
+   /// the text.
+   fn find_all(
+      & self,
+      text  : & str,
+   ) -> Result<Vec<crate::collect::FoundDate>, crate::date::ParseDateError> {
+      return self.find_all_with_rejected(text).map(|(dates, _)| dates);
+   }
+
+   /// Executes the same as Self::find_all, but
+   /// also returns every match which was rejected
+   /// because it isn't a valid date, such as
+   /// "February 30, 1995".  Rejected matches
+   /// are returned in the order they appear in
+   /// the text.
+   fn find_all_with_rejected(
+      & self,
+      text  : & str,
+   ) -> Result<(Vec<crate::collect::FoundDate>, Vec<RejectedMatch>), crate::date::ParseDateError>;
+}
+
+/// A regular expression match which was
+/// rejected because it isn't a valid date,
+/// along with the reason it was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RejectedMatch {
+   text     : String,
+   offset   : usize,
+   reason   : crate::date::ParseDateError,
+}
+
+/// A built-in date format which can be
+/// extracted from text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BuiltinFormat {
+   /// "Month Day(th)(,) Year", such as
+   /// "June 23rd, 1995" or "jun. 23 1995",
+   /// optionally followed by an era such as
+   /// "BC" and a time of day and time zone
+   /// such as "14:02 JST".
+   MonthDayYear,
+
+   /// "Day(th) (of) Month(,) Year", such as
+   /// "23 June 1995" or "23rd of June, 1995",
+   /// optionally followed by an era and a time
+   /// of day and time zone.  The year may be
+   /// spelled out, such as "nineteen ninety-five".
+   DayMonthYear,
+
+   /// Spelled-out ordinal days, such as
+   /// "the twenty-third of June, nineteen
+   /// ninety-five" or "the first day of May,
+   /// in the year 1995".
+   SpelledOut,
+
+   /// The format of the C __DATE__ macro,
+   /// such as "Jun 23 1995" or "Jun  3 1995".
+   CDate,
+
+   /// The format of the C __TIMESTAMP__ macro
+   /// and the ctime() function, such as
+   /// "Fri Jun 23 14:02:11 1995", optionally
+   /// followed by a time zone.
+   CTime,
+
+   /// The format of RFC 2822 email and HTTP
+   /// headers, such as
+   /// "Fri, 23 Jun 1995 14:02:11 +0900".
+   Rfc2822,
+
+   /// Date ranges within a year, such as
+   /// "June 23-25, 1995" or
+   /// "June 23 - July 2, 1995".
+   MonthDayRange,
+
+   /// Lists of days within a month, such as
+   /// "June 23, 25 and 30, 1995".
+   MonthDayList,
+
+   /// "Month(,) Year", such as "June 1995"
+   /// or "Jun. 1995", creating a partial date.
+   MonthYear,
+
+   /// "QN Year", such as "Q3 1995" or
+   /// "Q3/1995", creating a partial date.
+   Quarter,
+
+   /// Copyright notices such as "(c) 1995"
+   /// or "Copyright 1995", creating a year
+   /// only partial date.
+   Copyright,
+}
+
+/// Error type detailing a parsing error
+/// for BuiltinFormat.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseBuiltinFormatError {
+   /// A format matching the input text was not found.
+   NoMatchingFormat,
+}
+
+/// A set of regular expressions used
+/// together to extract dates from text.
+/// When matches from different regular
+/// expressions overlap, the match starting
+/// first is kept, followed by the longest
+/// match, followed by the regular expression
+/// added first.  Dates are created using
+/// the extractor's parse options, which set
+/// the calendar and whether misspelled months
+/// are recovered using fuzzy month matching.
+/// Each regular expression has a strictness
+/// used to score the confidence of its dates,
+/// see crate::confidence for more information.
+#[derive(Clone, Debug)]
+pub struct DateExtractor {
+   regex_list     : Vec<regex::Regex>,
+   strictness     : Vec<f64>,
+   author_list    : Vec<regex::Regex>,
+   options        : crate::date::ParseOptions,
+}
+
+///////////////
+// Constants //
+///////////////
+
+const RX_MONTH_ABBREVIATION : &str = "Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec";
+const RX_WEEKDAY_ABBREVIATION : &str = "Mon|Tue|Wed|Thu|Fri|Sat|Sun";
+const MAX_RANGE_DAYS : i64 = 366;
+const RX_ERA : &str = r"(?:BCE|BC|AD|CE)\b|B\.C\.(?:E\.)?|A\.D\.|C\.E\.";
+const RX_AUTHOR_PATTERNS : [&str; 2] = [
+   r"^,?\s*\b(?i:by)\s+(?P<a>[[:alpha:]][\w.@-]*)",
+   r"^\s*(?:-+|\x{2013})\s*(?P<a>[[:alpha:]][\w.@-]*):",
+];
+const RX_MONTH_NAME : &str = "January|February|March|April|May|June|July|August|September|October|November|December|Jan|Feb|Mar|Apr|Jun|Jul|Aug|Sept|Sep|Oct|Nov|Dec";
+
+/////////////////////////////////////
+// Trait implementations - Extract //
+/////////////////////////////////////
+
+impl Extract for regex::Regex {
+   fn find_all_with_rejected(
+      & self,
+      text  : & str,
+   ) -> Result<(Vec<crate::collect::FoundDate>, Vec<RejectedMatch>), crate::date::ParseDateError> {
+      use crate::date::ParseDateError::*;
+
+      let mut dates     = Vec::new();
+      let mut rejected  = Vec::new();
+      for cap in self.captures_iter(text) {
+         match internal_dates_from_captures(
+            text, &cap, &crate::date::ParseOptions::default(), crate::confidence::DEFAULT_STRICTNESS,
+         ) {
+            Ok(d)                      => dates.extend(d),
+            Err(InvalidRegexCaptures)  => return Err(InvalidRegexCaptures),
+            Err(e)                     => rejected.push(RejectedMatch::from_captures(&cap, e)),
+         }
+      }
+
+      return Ok((dates, rejected));
+   }
+}
+
+/////////////////////////////
+// Methods - RejectedMatch //
+/////////////////////////////
+
+impl RejectedMatch {
+   /// Creates a new RejectedMatch from the
+   /// matched text, the byte offset where it
+   /// starts, and the reason it was rejected.
+   pub fn new(
+      text     : String,
+      offset   : usize,
+      reason   : crate::date::ParseDateError,
+   ) -> Self {
+      return Self{
+         text     : text,
+         offset   : offset,
+         reason   : reason,
+      };
+   }
+
+   /// Creates a new RejectedMatch from the
+   /// captures of a regular expression match.
+   pub fn from_captures(
+      captures : & regex::Captures,
+      reason   : crate::date::ParseDateError,
+   ) -> Self {
+      let whole = captures.get(0);
+
+      return Self::new(
+         whole.map(|m| m.as_str()).unwrap_or("").to_string(),
+         whole.map(|m| m.start()).unwrap_or(0),
+         reason,
+      );
+   }
+
+   /// Creates a copy of the rejected match
+   /// with a different byte offset.
+   pub fn with_offset(
+      & self,
+      offset   : usize,
+   ) -> Self {
+      return Self{
+         offset   : offset,
+         ..self.clone()
+      };
+   }
+
+   /// Gets the matched text.
+   pub fn text<'l>(
+      &'l self,
+   ) -> &'l str {
+      return &self.text;
+   }
+
+   /// Gets the byte offset where the
+   /// match starts.
+   pub fn offset(
+      & self,
+   ) -> usize {
+      return self.offset;
+   }
+
+   /// Gets the reason the match was rejected.
+   pub fn reason(
+      & self,
+   ) -> crate::date::ParseDateError {
+      return self.reason;
+   }
+}
+
+///////////////////////////////////////////
+// Trait implementations - RejectedMatch //
+///////////////////////////////////////////
+
+impl std::fmt::Display for RejectedMatch {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "\"{}\" (offset {:#x}): {}", self.text, self.offset, self.reason);
+   }
+}
+
+/////////////////////////////
+// Methods - BuiltinFormat //
+/////////////////////////////
+
+impl BuiltinFormat {
+   /// A list of every built-in format, ordered
+   /// from most to least specific.
+   pub const ALL : [Self; 11] = [
+      Self::CTime,
+      Self::Rfc2822,
+      Self::CDate,
+      Self::MonthDayRange,
+      Self::MonthDayList,
+      Self::MonthDayYear,
+      Self::DayMonthYear,
+      Self::SpelledOut,
+      Self::MonthYear,
+      Self::Quarter,
+      Self::Copyright,
+   ];
+
+   /// Gets the name of the format as
+   /// used on the command line.
+   pub fn name(
+      & self,
+   ) -> &'static str {
+      return match self {
+         Self::MonthDayYear   => "month-day-year",
+         Self::DayMonthYear   => "day-month-year",
+         Self::SpelledOut     => "spelled-out",
+         Self::CDate          => "c-date",
+         Self::CTime          => "ctime",
+         Self::Rfc2822        => "rfc2822",
+         Self::MonthDayRange  => "month-day-range",
+         Self::MonthDayList   => "month-day-list",
+         Self::MonthYear      => "month-year",
+         Self::Quarter        => "quarter",
+         Self::Copyright      => "copyright",
+      };
+   }
+
+   /// Gets how strict the format is, from
+   /// 0.0 to 1.0, used to score the confidence
+   /// of found dates.  Formats with fixed
+   /// layouts, such as ctime, are the most
+   /// strict, while formats which are easily
+   /// found in prose are the least strict.
+   pub fn strictness(
+      & self,
+   ) -> f64 {
+      return match self {
+         Self::CTime          => 1.0,
+         Self::Rfc2822        => 1.0,
+         Self::CDate          => 0.9,
+         Self::SpelledOut     => 0.9,
+         Self::Copyright      => 0.9,
+         Self::MonthDayRange  => 0.8,
+         Self::MonthDayList   => 0.8,
+         Self::DayMonthYear   => 0.8,
+         Self::MonthDayYear   => 0.7,
+         Self::MonthYear      => 0.6,
+         Self::Quarter        => 0.6,
+      };
+   }
+
+   /// Creates the regular expression
+   /// used to find the format.
+   pub fn regex(
+      & self,
+   ) -> regex::Regex {
+      let months     = RX_MONTH_ABBREVIATION;
+      let weekdays   = RX_WEEKDAY_ABBREVIATION;
+      let names      = RX_MONTH_NAME;
+      let eras       = RX_ERA;
+      let zones      = internal_zone_pattern();
+      let ordinals   = crate::words::RX_ORDINAL_WORD;
+      let cardinals  = crate::words::RX_CARDINAL_WORD;
+      let words      = format!(r"(?i:{cardinals})(?:(?:\s+and\s+|[\s-]+)(?i:{cardinals}))+\b");
+
+      let pattern = match self {
+         Self::MonthDayYear   => format!(r"(?x)
+            (?P<m>[[:alpha:]]+)\.?\s*              # Month
+            (?P<d>\d{{1,2}})(?:st|nd|rd|th)?(?:\s*,\s*|\s+) # Day
+            (?P<y>[+-]?\d+)                        # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+            (?:,?\x20+                             # Optional time
+               (?P<H>\d{{1,2}}):(?P<M>\d{{2}})(?::(?P<S>\d{{2}}))?
+               (?:\x20*(?P<z>{zones}))?            # Optional time zone
+            )?
+         "),
+         Self::DayMonthYear   => format!(r"(?x)
+            \b(?:(?i:the)\s+)?                     # Optional article
+            (?P<d>\d{{1,2}})(?:st|nd|rd|th)?\s+   # Day
+            (?:(?i:of)\s+)?                        # Optional of
+            (?i:(?P<m>{names}))\.?,?\s+            # Month
+            (?P<y>\d{{4}}\b|{words})               # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+            (?:,?\x20+                             # Optional time
+               (?P<H>\d{{1,2}}):(?P<M>\d{{2}})(?::(?P<S>\d{{2}}))?
+               (?:\x20*(?P<z>{zones}))?            # Optional time zone
+            )?
+         "),
+         Self::SpelledOut     => format!(r"(?x)
+            \b(?:(?i:the)\s+)?                     # Optional article
+            (?P<d>(?i:(?:twenty|thirty)[\s-]?)?(?i:{ordinals}))\s+ # Day
+            (?:(?i:day)\s+)?(?i:of)\s+             # Of or day of
+            (?i:(?P<m>{names}))\.?,?\s+            # Month
+            (?:(?i:in\s+the\s+year)\s+)?           # Optional in the year
+            (?P<y>\d{{4}}\b|{words})               # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+         "),
+         Self::CDate          => format!(r"(?x)
+            \b(?P<m>{months})\x20{{1,2}}           # Month
+            (?P<d>\d{{1,2}})\x20                   # Day, padded with a space
+            (?P<y>\d{{4}})\b                       # Year
+         "),
+         Self::CTime          => format!(r"(?x)
+            \b(?:{weekdays})\x20                   # Weekday
+            (?P<m>{months})\x20{{1,2}}             # Month
+            (?P<d>\d{{1,2}})\x20                   # Day, padded with a space
+            (?P<H>\d{{2}}):(?P<M>\d{{2}}):(?P<S>\d{{2}})\x20 # Time
+            (?P<y>\d{{4}})\b                       # Year
+            (?:\x20(?P<z>{zones}))?                # Optional time zone
+         "),
+         Self::Rfc2822        => format!(r"(?x)
+            \b(?:(?:{weekdays}),\s*)?              # Optional weekday
+            (?P<d>\d{{1,2}})\s+                    # Day
+            (?P<m>{months})\s+                     # Month
+            (?P<y>\d{{4}})\s+                      # Year
+            (?P<H>\d{{2}}):(?P<M>\d{{2}})(?::(?P<S>\d{{2}}))?\b # Time
+            (?:\s+(?P<z>{zones}))?                 # Optional time zone
+         "),
+         Self::MonthDayRange  => format!(r"(?x)
+            \b(?P<m>[[:alpha:]]+)\.?\s+            # First month
+            (?P<d>\d{{1,2}})(?:st|nd|rd|th)?\s*     # First day
+            (?:-|\x{{2013}}|\bto\b|\bthrough\b)\s* # Separator
+            (?:(?P<m2>[[:alpha:]]+)\.?\s+)?        # Optional last month
+            (?P<d2>\d{{1,2}})(?:st|nd|rd|th)?      # Last day
+            (?:\s*,\s*|\s+)(?P<y>\d{{4}})\b       # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+         "),
+         Self::MonthDayList   => format!(r"(?x)
+            \b(?i:(?P<m>{names}))\.?\s+          # Month
+            (?P<d>\d{{1,2}})(?:st|nd|rd|th)?       # First day
+            (?P<l>(?:                             # Other days
+               (?:\s*,\s*(?:and\s+|&\s*)?|\s+(?:and|&)\s+)
+               \d{{1,2}}(?:st|nd|rd|th)?
+            )+)
+            (?:\s*,\s*|\s+)(?P<y>\d{{4}})\b       # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+         "),
+         Self::MonthYear      => format!(r"(?x)
+            \b(?i:(?P<m>{names}))\.?,?\s+          # Month
+            (?P<y>\d{{4}})\b                       # Year
+            (?:\x20?(?P<e>{eras}))?                # Optional era
+         "),
+         Self::Quarter        => String::from(r"(?x)
+            \bQ(?P<q>[1-4])(?:\s+|/|-)              # Quarter
+            (?P<y>\d{4})\b                         # Year
+         "),
+         Self::Copyright      => String::from(r"(?x)
+            (?:\([cC]\)|\x{A9}|\b(?i:copyright))  # Copyright notice
+            (?:\s*(?:\([cC]\)|\x{A9}))?\s*        # Optional symbol after the word
+            (?P<y>\d{4})\b                         # Year
+         "),
+      };
+
+      return regex::Regex::new(&pattern).unwrap(); // If we panic, the above regex is bugged
+   }
+}
+
+///////////////////////////////////////////
+// Trait implementations - BuiltinFormat //
+///////////////////////////////////////////
+
+impl std::fmt::Display for BuiltinFormat {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for BuiltinFormat {
+   type Err = ParseBuiltinFormatError;
+
+   fn from_str(
+      string : & str,
+   ) -> Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|f| f.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseBuiltinFormatError::NoMatchingFormat);
+   }
+}
+
+/////////////////////////////////////////////////////
+// Trait implementations - ParseBuiltinFormatError //
+/////////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseBuiltinFormatError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingFormat  => "No matching date format",
+      });
+   }
+}
+
+impl std::error::Error for ParseBuiltinFormatError {
+}
+
+/////////////////////////////
+// Methods - DateExtractor //
+/////////////////////////////
+
+impl DateExtractor {
+   /// Creates a new DateExtractor from a
+   /// list of regular expressions.  See
+   /// crate::Date::from_captures for the
+   /// required capture groups.  Each regular
+   /// expression has the default strictness,
+   /// and the default author patterns are used.
+   pub fn new(
+      regex_list : Vec<regex::Regex>,
+   ) -> Self {
+      return Self{
+         strictness     : vec![crate::confidence::DEFAULT_STRICTNESS; regex_list.len()],
+         regex_list     : regex_list,
+         author_list    : Self::default_author_patterns(),
+         options        : crate::date::ParseOptions::default(),
+      };
+   }
+
+   /// Creates the default author patterns,
+   /// which match "by NAME" and "- NAME:"
+   /// directly after a date.
+   pub fn default_author_patterns(
+   ) -> Vec<regex::Regex> {
+      return RX_AUTHOR_PATTERNS.iter()
+         .map(|rx| regex::Regex::new(rx).unwrap()) // If we panic, the above regex is bugged
+         .collect();
+   }
+
+   /// Creates a new DateExtractor from a
+   /// list of built-in formats.
+   pub fn from_builtin(
+      formats : & [BuiltinFormat],
+   ) -> Self {
+      let mut extractor = Self::new(Vec::new());
+      for format in formats {
+         extractor.push_builtin(*format);
+      }
+
+      return extractor;
+   }
+
+   /// Adds a regular expression to the
+   /// end of the list with the default
+   /// strictness.
+   pub fn push(
+      & mut self,
+      regex : regex::Regex,
+   ) -> & mut Self {
+      return self.push_with_strictness(regex, crate::confidence::DEFAULT_STRICTNESS);
+   }
+
+   /// Adds a regular expression to the end
+   /// of the list with the given strictness,
+   /// from 0.0 to 1.0.
+   pub fn push_with_strictness(
+      & mut self,
+      regex       : regex::Regex,
+      strictness  : f64,
+   ) -> & mut Self {
+      self.regex_list.push(regex);
+      self.strictness.push(strictness.clamp(0.0, 1.0));
+      return self;
+   }
+
+   /// Adds a built-in format to the end of
+   /// the list with the format's strictness.
+   pub fn push_builtin(
+      & mut self,
+      format : BuiltinFormat,
+   ) -> & mut Self {
+      return self.push_with_strictness(format.regex(), format.strictness());
+   }
+
+   /// Sets the maximum edit distance used to
+   /// recover misspelled months, or disables
+   /// fuzzy month matching if None.  See
+   /// crate::date::Month::from_str_fuzzy.
+   pub fn set_fuzzy_months(
+      & mut self,
+      max_distance : Option<usize>,
+   ) -> & mut Self {
+      self.options.fuzzy_months = max_distance;
+      return self;
+   }
+
+   /// Gets the maximum edit distance used
+   /// for fuzzy month matching, if enabled.
+   pub fn fuzzy_months(
+      & self,
+   ) -> Option<usize> {
+      return self.options.fuzzy_months;
+   }
+
+   /// Sets the calendar found dates are
+   /// written in.
+   pub fn set_calendar(
+      & mut self,
+      calendar : crate::date::Calendar,
+   ) -> & mut Self {
+      self.options.calendar = calendar;
+      return self;
+   }
+
+   /// Gets the calendar found dates are
+   /// written in.
+   pub fn calendar(
+      & self,
+   ) -> crate::date::Calendar {
+      return self.options.calendar;
+   }
+
+   /// Sets the regular expressions matched
+   /// against the rest of the line after each
+   /// date to find its author, which is taken
+   /// from the capture group $a.  The first
+   /// pattern which matches is used.
+   pub fn set_author_patterns(
+      & mut self,
+      author_list : Vec<regex::Regex>,
+   ) -> & mut Self {
+      self.author_list = author_list;
+      return self;
+   }
+
+   /// Gets a reference to the list of
+   /// author patterns.
+   pub fn author_patterns<'l>(
+      &'l self,
+   ) -> &'l [regex::Regex] {
+      return &self.author_list;
+   }
+
+   /// Gets a reference to the list of
+   /// regular expressions.
+   pub fn regex_list<'l>(
+      &'l self,
+   ) -> &'l [regex::Regex] {
+      return &self.regex_list;
+   }
+}
+
+///////////////////////////////////////////
+// Trait implementations - DateExtractor //
+///////////////////////////////////////////
+
+impl std::default::Default for DateExtractor {
+   /// Creates a DateExtractor using every
+   /// built-in format.
+   fn default(
+   ) -> Self {
+      return Self::from_builtin(&BuiltinFormat::ALL);
+   }
+}
+
+impl std::convert::From<regex::Regex> for DateExtractor {
+   fn from(
+      regex : regex::Regex,
+   ) -> Self {
+      return Self::new(vec![regex]);
+   }
+}
+
+impl Extract for DateExtractor {
+   fn find_all_with_rejected(
+      & self,
+      text  : & str,
+   ) -> Result<(Vec<crate::collect::FoundDate>, Vec<RejectedMatch>), crate::date::ParseDateError> {
+      use crate::date::ParseDateError::*;
+
+      // Collect every match as (start, end, priority, dates or rejection)
+      let mut candidates = Vec::new();
+      let mut rejections = Vec::new();
+      for (priority, (regex, strictness)) in self.regex_list.iter().zip(&self.strictness).enumerate() {
+         for cap in regex.captures_iter(text) {
+            let span = cap.get(0).map(|m| m.range()).unwrap_or(0..0);
+            match internal_dates_from_captures(text, &cap, &self.options, *strictness) {
+               Ok(d)                      => candidates.push((
+                  span.start, span.end, priority, self.internal_with_author(text, span.end, d),
+               )),
+               Err(InvalidRegexCaptures)  => return Err(InvalidRegexCaptures),
+               Err(e)                     => rejections.push((
+                  span.start, span.end, priority, RejectedMatch::from_captures(&cap, e),
+               )),
+            };
+         }
+      }
+
+      // Earliest start wins, then longest match, then priority
+      candidates.sort_by(|a, b| {
+         a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then(a.2.cmp(&b.2))
+      });
+      rejections.sort_by(|a, b| {
+         a.0.cmp(&b.0).then(b.1.cmp(&a.1)).then(a.2.cmp(&b.2))
+      });
+
+      // Keep every match which doesn't overlap a kept match
+      let mut dates  = Vec::new();
+      let mut spans  = Vec::new();
+      let mut end_last = 0;
+      for (start, end, _, found) in candidates {
+         if start < end_last {
+            continue;
+         }
+
+         dates.extend(found);
+         spans.push(start..end);
+         end_last = end;
+      }
+
+      // Keep every rejection which doesn't overlap
+      // a found date or another kept rejection
+      let mut rejected = Vec::new();
+      let mut end_last = 0;
+      for (start, end, _, rejection) in rejections {
+         if start < end_last || spans.iter().any(|s| start < s.end && s.start < end) {
+            continue;
+         }
+
+         rejected.push(rejection);
+         end_last = end;
+      }
+
+      return Ok((dates, rejected));
+   }
+}
+
+//////////////////////////////////////
+// Internal helpers - DateExtractor //
+//////////////////////////////////////
+
+impl DateExtractor {
+   /// Sets the author of dates which don't
+   /// have one using the first author pattern
+   /// which matches the text directly after
+   /// the date's match.
+   fn internal_with_author(
+      & self,
+      text  : & str,
+      end   : usize,
+      dates : Vec<crate::collect::FoundDate>,
+   ) -> Vec<crate::collect::FoundDate> {
+      if dates.iter().all(|d| d.author().is_some()) {
+         return dates;
+      }
+
+      // Authors are only searched for on the rest of the date's line
+      let line = &text[end..];
+      let line = &line[..line.find('\n').unwrap_or(line.len())];
+      let author = self.author_list.iter()
+         .find_map(|rx| rx.captures(line))
+         .and_then(|cap| cap.name("a").map(|a| internal_author_name(a.as_str())));
+      if author.is_none() {
+         return dates;
+      }
+
+      return dates.into_iter()
+         .map(|d| match d.author() {
+            Some(_)  => d,
+            None     => d.with_author(author.clone()),
+         })
+         .collect();
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Cleans up a captured author name,
+/// removing trailing punctuation.
+fn internal_author_name(
+   author : & str,
+) -> String {
+   return author.trim().trim_end_matches(['.', '-', ',']).to_string();
+}
+
+/// Creates a regular expression pattern
+/// matching a UTC offset or any known time
+/// zone abbreviation.
+fn internal_zone_pattern(
+) -> String {
+   let mut abbreviations = crate::date::TIME_ZONE_ABBREVIATIONS.iter()
+      .map(|(a, _)| *a)
+      .collect::<Vec<_>>();
+
+   // Longer abbreviations are matched first
+   abbreviations.sort_by_key(|a| std::cmp::Reverse(a.len()));
+
+   return format!(r"[+-]\d{{2}}(?::?\d{{2}})?\b|(?:{})\b", abbreviations.join("|"));
+}
+
+/// Creates every date described by the
+/// captures of a regular expression match,
+/// expanding date ranges and lists.  See the
+/// module documentation for the capture groups.
+/// Dates are created using the given options,
+/// and scored using the text the captures were
+/// found in and the regular expression's
+/// strictness.
+fn internal_dates_from_captures(
+   text        : & str,
+   captures    : & regex::Captures,
+   options     : & crate::date::ParseOptions,
+   strictness  : f64,
+) -> Result<Vec<crate::collect::FoundDate>, crate::date::ParseDateError> {
+   use crate::{
+      collect::FoundDate,
+      date::{Date, Month, Precision, ParseDateError::*},
+   };
+
+   let span             = captures.get(0).map(|m| m.range()).unwrap_or(0..0);
+   let length           = span.len();
+   let offset           = captures.get(0).map(|m| m.start());
+   let (first, mut fuzzy) = Date::from_captures_with(captures, options)?;
+   let confidence       = crate::confidence::score(text, span, &first, strictness);
+   let author           = captures.name("a").map(|a| internal_author_name(a.as_str()));
+
+   // Find the rest of the dates in a range or list
+   let rest = if let Some(day_last) = captures.name("d2") {
+      let day_last   = day_last.as_str().parse().map_err(|_| InvalidDayFormatting)?;
+      let month_last = match captures.name("m2").map(|m| m.as_str()) {
+         Some(m)  => match (m.parse(), options.fuzzy_months) {
+            (Ok(month), _)             => month,
+            (Err(_), Some(distance))   => {
+               fuzzy = true;
+               Month::from_str_fuzzy(m, distance).map_err(|_| InvalidMonthFormatting)?
+            },
+            (Err(_), None)             => return Err(InvalidMonthFormatting),
+         },
+         None     => first.month(),
+      };
+
+      // Ranges ending in an earlier month end in the next year
+      let calendar   = first.calendar();
+      let year_last  = match captures.name("y2") {
+         Some(y)                             => y.as_str().parse().map_err(|_| InvalidYearFormatting)?,
+         None if month_last < first.month()  => calendar.from_astronomical_year(calendar.astronomical_year(first.year()) + 1),
+         None                                => first.year(),
+      };
+      let last       = Date::new_in(day_last, month_last, year_last, first.calendar())?;
+
+      let days_first = first.days_since_epoch();
+      let days_last  = last .days_since_epoch();
+      if days_last <= days_first || days_last - days_first > MAX_RANGE_DAYS {
+         return Err(InvalidRange);
+      }
+
+      (days_first + 1 ..= days_last)
+         .map(|days| Date::from_days_since_epoch_in(days, first.calendar()))
+         .collect()
+   } else if let Some(list) = captures.name("l") {
+      list.as_str()
+         .split(|c : char| c.is_ascii_digit() == false)
+         .filter(|day| day.is_empty() == false)
+         .map(|day| Date::new_in(
+            day.parse().map_err(|_| InvalidDayFormatting)?, first.month(), first.year(), first.calendar(),
+         ))
+         .collect::<Result<Vec<_>, _>>()?
+   } else {
+      Vec::new()
+   };
+
+   // Single dates aren't part of a range
+   if rest.is_empty() {
+      return Ok(vec![FoundDate::new(first, offset)
+         .with_fuzzy(fuzzy)
+         .with_confidence(confidence)
+         .with_author(author)
+         .with_length(length)
+      ]);
+   }
+   if first.precision() != Precision::Day {
+      return Err(InvalidRange);
+   }
+
+   let mut dates = vec![first];
+   dates.extend(rest);
+
+   let range_first   = *dates.iter().min().unwrap(); // Never empty
+   let range_last    = *dates.iter().max().unwrap(); // Never empty
+   return Ok(dates.into_iter()
+      .map(|date| FoundDate::new(date, offset)
+         .with_range(range_first, range_last)
+         .with_fuzzy(fuzzy)
+         .with_confidence(confidence)
+         .with_author(author.clone())
+         .with_length(length)
+      )
+      .collect()
+   );
+}
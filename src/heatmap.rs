@@ -0,0 +1,242 @@
+//! Utilities for drawing a calendar heatmap
+//! of the dates found in files.
+//!
+//! Each year is drawn as a grid with a column for
+//! every week and a row for every day of the week,
+//! where each day is shaded by how many dates fall
+//! on it.  Busy periods stand out as dense, bright
+//! clusters.  Only dates known to the day are
+//! counted, and days are taken in the proleptic
+//! Gregorian calendar.
+
+///////////////
+// Constants //
+///////////////
+
+/// The maximum amount of years a heatmap
+/// may cover.
+pub const MAX_YEARS : usize = 100;
+
+/// The characters used for each level of
+/// activity, from none to the most, so the
+/// heatmap is readable without color.
+const LEVEL_CHARACTERS : [char; 5] = [
+   '\u{00B7}', '\u{2591}', '\u{2592}', '\u{2593}', '\u{2588}',
+];
+
+/// The colors used for each level of
+/// activity, from none to the most.
+const LEVEL_COLORS : [(u8, u8, u8); 5] = [
+   (88,  88,  88),
+   (14,  68,  41),
+   (0,   109, 50),
+   (38,  166, 65),
+   (57,  211, 83),
+];
+
+/// The width of the weekday labels at
+/// the start of each row.
+const LABEL_WIDTH : usize = 4;
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// Error type detailing a parsing error
+/// for a heatmap's year range.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseYearRangeError {
+   /// The text isn't written as "YEAR" or
+   /// "FIRST-LAST".
+   InvalidFormatting,
+
+   /// The last year is before the first
+   /// year, a year is before 1 CE, or there
+   /// are more than MAX_YEARS years.
+   InvalidRange,
+}
+
+/// The amount of dates found on each day
+/// within a range of years.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Heatmap {
+   years    : std::ops::RangeInclusive<isize>,
+   counts   : std::collections::BTreeMap<i64, usize>,
+}
+
+/////////////////////////////////////////////////
+// Trait implementations - ParseYearRangeError //
+/////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseYearRangeError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::InvalidFormatting => "Year range must be written as YEAR or FIRST-LAST",
+         Self::InvalidRange      => "Year range is backwards, before 1 CE, or too long",
+      });
+   }
+}
+
+impl std::error::Error for ParseYearRangeError {
+}
+
+///////////////////////
+// Methods - Heatmap //
+///////////////////////
+
+impl Heatmap {
+   /// Counts the dates on each day within
+   /// the given range of years for every file.
+   pub fn from_files(
+      file_data   : & crate::collect::FileAggregateDateList,
+      years       : std::ops::RangeInclusive<isize>,
+   ) -> Self {
+      use crate::date::{Date, Precision};
+
+      let mut counts = std::collections::BTreeMap::new();
+      for file in file_data.iter() {
+         for date in file.dates().iter().map(|d| d.date()) {
+            let days = date.days_since_epoch();
+            if date.precision() == Precision::Day && years.contains(&Date::from_days_since_epoch(days).year()) {
+               *counts.entry(days).or_insert(0) += 1;
+            }
+         }
+      }
+
+      return Self{
+         years    : years,
+         counts   : counts,
+      };
+   }
+
+   /// Parses a range of years written as
+   /// "YEAR" or "FIRST-LAST", such as
+   /// "1995-1997".
+   pub fn parse_years(
+      text  : & str,
+   ) -> Result<std::ops::RangeInclusive<isize>, ParseYearRangeError> {
+      let parse = |year : &str| year.trim().parse::<isize>()
+         .map_err(|_| ParseYearRangeError::InvalidFormatting);
+
+      let (first, last) = match text.split_once('-') {
+         Some((first, last)) => (parse(first)?, parse(last)?),
+         None                => (parse(text)?,  parse(text)?),
+      };
+      if first < 1 || last < first || (last - first) as usize >= MAX_YEARS {
+         return Err(ParseYearRangeError::InvalidRange);
+      }
+
+      return Ok(first..=last);
+   }
+
+   /// Gets the range of years covered
+   /// by the heatmap.
+   pub fn years(
+      & self,
+   ) -> std::ops::RangeInclusive<isize> {
+      return self.years.clone();
+   }
+
+   /// Gets the amount of dates found
+   /// on the given day.
+   pub fn count(
+      & self,
+      date  : crate::date::Date,
+   ) -> usize {
+      return self.counts.get(&date.days_since_epoch()).copied().unwrap_or(0);
+   }
+
+   /// Gets the most dates found on
+   /// any single day.
+   pub fn max_count(
+      & self,
+   ) -> usize {
+      return self.counts.values().copied().max().unwrap_or(0);
+   }
+
+   /// Gets the level of activity from 0 to 4
+   /// for an amount of dates on a day, relative
+   /// to the busiest day.
+   pub fn level(
+      & self,
+      count : usize,
+   ) -> usize {
+      let max = self.max_count().max(1);
+      return (count * (LEVEL_CHARACTERS.len() - 1)).div_ceil(max).min(LEVEL_CHARACTERS.len() - 1);
+   }
+}
+
+/////////////////////////////////////
+// Trait implementations - Heatmap //
+/////////////////////////////////////
+
+impl std::fmt::Display for Heatmap {
+   /// Draws every year as a grid of weeks by
+   /// weekdays, followed by a legend.  Colors
+   /// follow the colored crate's global
+   /// settings.
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      use crate::date::{Calendar, Date, Month, Weekday};
+
+      for year in self.years() {
+         let start = Date::new_year(year).days_since_epoch();
+         let end   = start + if Calendar::Gregorian.is_leap_year(year) {366} else {365};
+         let first = start - Date::new_year(year).day_of_week() as i64;
+         let weeks = (end - first + 6) / 7;
+
+         // Year and month labels above their first week
+         let mut months = " ".repeat(LABEL_WIDTH + weeks as usize * 2);
+         for month in Month::ALL {
+            let column = (Date::new_month(month, year).days_since_epoch() - first) / 7;
+            let column = LABEL_WIDTH + column as usize * 2;
+            months.replace_range(column..column + 3, &month.to_string()[..3]);
+         }
+         write!(stream, "{}\n{}\n", Date::new_year(year), months.trim_end())?;
+
+         for weekday in Weekday::ALL {
+            write!(stream, "{:<LABEL_WIDTH$}", weekday.abbreviation())?;
+            for week in 0..weeks {
+               let day = first + week * 7 + weekday as i64;
+               if day < start || day >= end {
+                  write!(stream, "  ")?;
+                  continue;
+               }
+
+               write!(stream, "{} ", internal_cell(self.level(self.count(Date::from_days_since_epoch(day)))))?;
+            }
+            write!(stream, "\n")?;
+         }
+         write!(stream, "\n")?;
+      }
+
+      write!(stream, "Less ")?;
+      for level in 0..LEVEL_CHARACTERS.len() {
+         write!(stream, "{} ", internal_cell(level))?;
+      }
+      return write!(stream, "More (busiest day has {} date{})\n",
+         self.max_count(),
+         if self.max_count() == 1 {""} else {"s"},
+      );
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Creates the colored character for a
+/// level of activity.
+fn internal_cell(
+   level : usize,
+) -> colored::ColoredString {
+   use colored::Colorize;
+
+   let (r, g, b) = LEVEL_COLORS[level];
+   return LEVEL_CHARACTERS[level].to_string().truecolor(r, g, b);
+}
@@ -0,0 +1,221 @@
+//! Utilities for writing the dates found in
+//! files as a self-contained HTML page.
+//!
+//! The page is meant to be shared with people who
+//! won't run the tool themselves, so everything it
+//! needs is written inline without any network
+//! assets.  It contains a timeline of the span
+//! between each file's first and last date, a
+//! histogram of the dates found per year, and a
+//! table of files which sorts when a column
+//! header is clicked.
+
+///////////////
+// Constants //
+///////////////
+
+/// The style sheet written inline into
+/// every page.
+const STYLE : &str = "
+body { font-family: sans-serif; margin: 2em; color: #222; }
+h1 { font-size: 1.6em; }
+h2 { font-size: 1.2em; margin-top: 2em; border-bottom: 1px solid #ccc; }
+.row { display: flex; align-items: center; margin: 2px 0; }
+.label { width: 30%; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; font-family: monospace; font-size: 0.85em; }
+.track { position: relative; flex: 1; height: 14px; background: #f2f2f2; }
+.span { position: absolute; height: 100%; min-width: 3px; background: #3a7bd5; }
+.span:hover, .bar:hover { background: #e8710a; }
+.axis { display: flex; justify-content: space-between; margin-left: 30%; font-size: 0.8em; color: #666; }
+.histogram { display: flex; align-items: flex-end; height: 200px; gap: 2px; border-bottom: 1px solid #999; }
+.column { display: flex; flex-direction: column; justify-content: flex-end; flex: 1; height: 100%; }
+.bar { background: #3a7bd5; }
+.years { display: flex; gap: 2px; font-size: 0.7em; color: #666; }
+.years span { flex: 1; text-align: center; overflow: hidden; }
+table { border-collapse: collapse; width: 100%; font-size: 0.9em; }
+th, td { border: 1px solid #ddd; padding: 4px 8px; text-align: left; }
+th { background: #f2f2f2; cursor: pointer; user-select: none; }
+th[data-order=ascending]::after { content: \" \\25B2\"; }
+th[data-order=descending]::after { content: \" \\25BC\"; }
+td.path { font-family: monospace; }
+";
+
+/// The script written inline into every
+/// page, which sorts the table of files by
+/// the column whose header was clicked.
+const SCRIPT : &str = "
+document.querySelectorAll('th').forEach(function(header, column) {
+   header.addEventListener('click', function() {
+      var body = header.closest('table').tBodies[0];
+      var ascending = header.dataset.order !== 'ascending';
+      header.parentNode.querySelectorAll('th').forEach(function(h) { delete h.dataset.order; });
+      header.dataset.order = ascending ? 'ascending' : 'descending';
+
+      var rows = Array.from(body.rows);
+      rows.sort(function(a, b) {
+         var x = a.cells[column].dataset.sort;
+         var y = b.cells[column].dataset.sort;
+         var order = (isNaN(x) || isNaN(y)) ? x.localeCompare(y) : x - y;
+         return ascending ? order : -order;
+      });
+      rows.forEach(function(row) { body.appendChild(row); });
+   });
+});
+";
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// A self-contained HTML page showing the
+/// dates found in a set of files, written
+/// with the Display trait.
+pub struct HtmlReport<'l> {
+   raw_data : &'l crate::collect::FileAggregateDateList,
+}
+
+//////////////////////////
+// Methods - HtmlReport //
+//////////////////////////
+
+impl<'l> HtmlReport<'l> {
+   /// Creates a new HtmlReport for the
+   /// dates found in a set of files.
+   pub fn from(
+      file_data   : &'l crate::collect::FileAggregateDateList,
+   ) -> Self {
+      return Self{
+         raw_data : file_data,
+      };
+   }
+}
+
+////////////////////////////////////////
+// Trait implementations - HtmlReport //
+////////////////////////////////////////
+
+impl<'l> std::fmt::Display for HtmlReport<'l> {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      // Find the span of every file with dates
+      let files = self.raw_data.iter()
+         .filter_map(|fd| {
+            let first = fd.dates().first()?.date();
+            let last  = fd.dates().last()?.date();
+            return Some((fd, first, last));
+         })
+         .collect::<Vec<_>>();
+
+      let date_count = files.iter().map(|(fd, _, _)| fd.dates().len()).sum::<usize>();
+      let earliest   = files.iter().map(|(_, first, _)| *first).min_by_key(|d| d.days_since_epoch());
+      let latest     = files.iter().map(|(_, _, last)| *last).max_by_key(|d| d.days_since_epoch());
+
+      write!(stream, "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n")?;
+      write!(stream, "<title>Dacom Report</title>\n<style>{STYLE}</style>\n</head>\n<body>\n")?;
+      write!(stream, "<h1>Dacom Report</h1>\n")?;
+      write!(stream,
+         "<p>{} dates found in {} of {} files.</p>\n",
+         date_count,
+         files.len(),
+         self.raw_data.len(),
+      )?;
+
+      let (earliest, latest) = match (earliest, latest) {
+         (Some(earliest), Some(latest))   => (earliest, latest),
+         _                                => return write!(stream, "</body>\n</html>\n"),
+      };
+      let span = (latest.days_since_epoch() - earliest.days_since_epoch()).max(1) as f64;
+
+      // Timeline of each file's span
+      write!(stream, "<h2>Timeline</h2>\n")?;
+      for (fd, first, last) in files.iter() {
+         let path  = escape(fd.path().to_str().unwrap_or("???"));
+         let left  = (first.days_since_epoch() - earliest.days_since_epoch()) as f64 / span * 100.0;
+         let width = (last.days_since_epoch() - first.days_since_epoch()) as f64 / span * 100.0;
+
+         write!(stream,
+            "<div class=\"row\"><div class=\"label\" title=\"{path}\">{path}</div><div class=\"track\">\
+            <div class=\"span\" style=\"left: {left:.3}%; width: {width:.3}%\" title=\"{path}: {} to {}\"></div>\
+            </div></div>\n",
+            escape(&first.to_string()),
+            escape(&last.to_string()),
+         )?;
+      }
+      write!(stream,
+         "<div class=\"axis\"><span>{}</span><span>{}</span></div>\n",
+         escape(&earliest.to_string()),
+         escape(&latest.to_string()),
+      )?;
+
+      // Histogram of dates per year
+      let mut years = std::collections::BTreeMap::<isize, usize>::new();
+      for (fd, _, _) in files.iter() {
+         for date in fd.dates().iter() {
+            *years.entry(date.date().year()).or_insert(0) += 1;
+         }
+      }
+      let max = years.values().copied().max().unwrap_or(1);
+
+      write!(stream, "<h2>Dates per Year</h2>\n<div class=\"histogram\">\n")?;
+      for (year, count) in years.iter() {
+         write!(stream,
+            "<div class=\"column\"><div class=\"bar\" style=\"height: {:.3}%\" title=\"{year}: {count} date{}\"></div></div>\n",
+            *count as f64 / max as f64 * 100.0,
+            if *count == 1 {""} else {"s"},
+         )?;
+      }
+      write!(stream, "</div>\n<div class=\"years\">")?;
+      for year in years.keys() {
+         write!(stream, "<span>{year}</span>")?;
+      }
+      write!(stream, "</div>\n")?;
+
+      // Sortable table of files
+      write!(stream, "<h2>Files</h2>\n<table>\n<thead><tr>")?;
+      write!(stream, "<th>Path</th><th>Dates</th><th>First Date</th><th>Last Date</th><th>Span (days)</th>")?;
+      write!(stream, "</tr></thead>\n<tbody>\n")?;
+      for (fd, first, last) in files.iter() {
+         let path = escape(fd.path().to_str().unwrap_or("???"));
+         let days = last.days_since_epoch() - first.days_since_epoch();
+
+         write!(stream,
+            "<tr><td class=\"path\" data-sort=\"{path}\">{path}</td><td data-sort=\"{count}\">{count}</td>\
+            <td data-sort=\"{}\">{}</td><td data-sort=\"{}\">{}</td><td data-sort=\"{days}\">{days}</td></tr>\n",
+            first.days_since_epoch(),
+            escape(&first.to_string()),
+            last.days_since_epoch(),
+            escape(&last.to_string()),
+            count = fd.dates().len(),
+         )?;
+      }
+      write!(stream, "</tbody>\n</table>\n")?;
+
+      return write!(stream, "<script>{SCRIPT}</script>\n</body>\n</html>\n");
+   }
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Escapes text so it can be written
+/// inside of HTML or SVG elements and
+/// quoted attributes.
+pub fn escape(
+   text  : & str,
+) -> String {
+   let mut escaped = String::with_capacity(text.len());
+   for c in text.chars() {
+      match c {
+         '&'   => escaped.push_str("&amp;"),
+         '<'   => escaped.push_str("&lt;"),
+         '>'   => escaped.push_str("&gt;"),
+         '"'   => escaped.push_str("&quot;"),
+         '\''  => escaped.push_str("&#39;"),
+         _     => escaped.push(c),
+      }
+   }
+
+   return escaped;
+}
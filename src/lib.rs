@@ -5,20 +5,45 @@
 //! in this crate.
 
 // Public interfaces
+pub mod archive;
 pub mod args;
+pub mod category;
+pub mod changelog;
 pub mod collect;
+pub mod comments;
+pub mod confidence;
 pub mod date;
+pub mod decompress;
+pub mod diff;
+pub mod directory;
+pub mod extract;
+pub mod heatmap;
+pub mod html;
+pub mod markdown;
 pub mod report;
+pub mod scan;
+pub mod sort;
+pub mod strings;
+pub mod svg;
+pub mod words;
 
 // Re-exports
 pub use args::Args;
+pub use category::DateCategory;
 pub use collect::CollectDateError;
 pub use collect::DateFinderThreadPool;
 pub use collect::DateList;
 pub use collect::FileDateList;
 pub use collect::FileAggregateDateList;
+pub use collect::SearchOptions;
 pub use date::Month;
+pub use date::Calendar;
 pub use date::Date;
+pub use date::Precision;
+pub use date::Time;
+pub use date::TimeZone;
+pub use date::Weekday;
+pub use extract::DateExtractor;
 pub use report::FileAggregateReport;
 
 // Unit tests
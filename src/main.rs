@@ -16,61 +16,181 @@ fn main() {
 fn dacom_main() -> Result<(), Box<dyn std::error::Error>> {
    // Parse command-line arguments
    let args = dacom::Args::new(std::env::args());
+
+   // Compare two saved scans instead of searching
+   if let Some((before, after)) = args.diff() {
+      if args.verbose() {println!(
+         "Comparing scans {before} and {after}...",
+      )};
+      let before  = dacom::scan::from_json(&std::fs::read_to_string(before)?)?;
+      let after   = dacom::scan::from_json(&std::fs::read_to_string(after)?)?;
+      let diff    = dacom::diff::ScanDiff::new(&before, &after).to_format(args.format())?;
+
+      if let Some(path) = args.output_file() {
+         if args.verbose() {println!(
+            "Writing results to {path}...",
+         )};
+         std::fs::write(path, diff)?;
+      } else {
+         print!("{diff}");
+      }
+      return Ok(());
+   }
   
    // Get thread count, defaulting to CPU core count
    let thread_count = args.threads().unwrap_or(
       std::thread::available_parallelism()?,
    );
 
-   // Compile the input regex or use the default one
-   if args.verbose() {if let Some(rx) = args.date_regex() {println!(
-      "Using regex \"{rx}\" to search for dates",
-   )}};
-   let regex = regex::Regex::new(args.date_regex().unwrap_or(r"(?x)
-      ######## Format #########
-      # Month Day(th)(,) Year #
-      #########################
-
-      (?P<m>[[:alpha:]]+)\.?\s*              # Month
-      (?P<d>\d{1,2})(?:st|nd|rd|th)?\s*,?\s* # Day
-      (?P<y>[+-]?\d+)                        # Year
-   "))?;
+   // Compile the input regex and built-in formats,
+   // using every built-in format by default
+   let mut extractor = dacom::DateExtractor::new(Vec::new());
+   if let Some(rx) = args.date_regex() {
+      if args.verbose() {println!(
+         "Using regex \"{rx}\" to search for dates",
+      )};
+      extractor.push(regex::Regex::new(rx)?);
+   }
+   let formats = match args.date_regex().is_none() && args.extractors().is_empty() {
+      true  => &dacom::extract::BuiltinFormat::ALL[..],
+      false => args.extractors(),
+   };
+   for format in formats {
+      if args.verbose() {println!(
+         "Using built-in format {format} to search for dates",
+      )};
+      extractor.push_builtin(*format);
+   }
+   if args.author_regex().is_empty() == false {
+      extractor.set_author_patterns(args.author_regex().iter()
+         .map(|rx| regex::Regex::new(rx))
+         .collect::<Result<_, _>>()?
+      );
+   }
+   extractor.set_fuzzy_months(args.fuzzy_months());
+   extractor.set_calendar(args.calendar());
 
    // Create the thread pool
    if args.verbose() {println!(
       "Creating a thread pool with {} threads...",
       thread_count,
    )};
-   let mut thread_pool = dacom::DateFinderThreadPool::new(
-      thread_count, regex,
+   let options = dacom::SearchOptions{
+      archives          : args.archives(),
+      decompress        : args.decompress(),
+      size_limit        : args.size_limit().unwrap_or(dacom::decompress::DEFAULT_SIZE_LIMIT),
+      binary_strings    : args.binary_strings(),
+      min_string_length : args.min_string_length().unwrap_or(dacom::strings::DEFAULT_MIN_LENGTH),
+      near_misses       : args.near_misses(),
+      min_confidence    : args.min_confidence(),
+      comments_only     : args.comments_only(),
+      keywords          : args.keywords(),
+      changelog         : args.changelog(),
+   };
+   let mut thread_pool = dacom::DateFinderThreadPool::new_with_options(
+      thread_count, extractor.clone(), options.clone(),
+   );
+
+   // Gather the input roots, splitting off stdin
+   let mut input_roots = Vec::new();
+   if args.files_from_stdin() {
+      if args.verbose() {println!(
+         "Reading list of input files from stdin...",
+      )};
+      input_roots.extend(dacom::collect::read_path_list(std::io::stdin().lock())?);
+   }
+   let search_stdin = args.input_files().iter().any(|f| f == dacom::args::STDIN_PATH);
+   input_roots.extend(args.input_files().iter()
+      .filter(|f| f.as_str() != dacom::args::STDIN_PATH)
+      .map(std::path::PathBuf::from)
    );
 
    // Collect dates from files
    if args.verbose() {println!(
-      "Starting search for and collection of dates starting at {}...",
-      args.input_file(),
+      "Starting search for and collection of dates starting at {} input root(s)...",
+      input_roots.len(),
    )};
-   let data = dacom::FileAggregateDateList::new_recursive_with(
+   let mut data = dacom::FileAggregateDateList::new_recursive_multi_with(
       & mut thread_pool,
-      args.input_file(),
+      input_roots,
       |path| if args.verbose() {println!(
          "Searching {}...",
          path.to_str().unwrap_or("(???)"),
       )},
    )?;
+
+   // Collect dates from stdin as a single pseudo-file
+   if search_stdin {
+      if args.verbose() {println!(
+         "Searching stdin...",
+      )};
+      let stdin_dates = dacom::FileDateList::from_reader_with_options(
+         std::path::PathBuf::from("<stdin>"),
+         std::io::stdin().lock(),
+         &extractor,
+         &options,
+      );
+
+      // Skip unreadable data the same as searched files
+      match stdin_dates {
+         Ok(fd)   => {data.insert(fd);},
+         Err(e @ (dacom::collect::CollectDateError::InvalidData | dacom::collect::CollectDateError::DataTooLarge))
+            => if args.verbose() {println!(
+               "Skipping stdin: {e}",
+            )},
+         Err(e)   => return Err(e.into()),
+      }
+   }
    
+   // Save the scan for later comparison
+   if let Some(path) = args.save_scan() {
+      if args.verbose() {println!(
+         "Saving scan to {path}...",
+      )};
+      std::fs::write(path, dacom::scan::to_json(&data))?;
+   }
+
    // Create a data report
    if args.verbose() {println!(
       "Analyzing found dates and creating a report...",
    )};
-   let data = data.create_report()?;
+   let data = match args.category() {
+      Some(category) => data.create_report_for(category)?,
+      None           => data.create_report()?,
+   };
+   let data = match args.directory_depth() {
+      Some(depth)    => data.with_directory_depth(depth),
+      None           => data,
+   };
+   let data = match args.heatmap() {
+      Some(years)    => data.with_heatmap(years),
+      None           => data,
+   };
+   let data = match args.top() {
+      Some(count)    => data.with_top(count),
+      None           => data,
+   };
+   let data = match args.max_dates() {
+      Some(limit)    => data.with_date_limit(limit),
+      None           => data,
+   };
+
+   // Colors are only meant for the terminal
+   if args.output_file().is_some() {
+      colored::control::set_override(false);
+   }
+   let data = data
+      .with_sort_order(args.sort_order(), args.reverse())
+      .with_summary_only(args.summary_only())
+      .with_svg_options(args.svg_options())
+      .to_format(args.format());
 
    // Send the data to the appropriate file stream
    if let Some(path) = args.output_file() {
       if args.verbose() {println!(
          "Writing results to {path}...",
       )};
-      std::fs::write(path, data.to_string())?;
+      std::fs::write(path, data)?;
    } else {
       print!("{data}");
    }
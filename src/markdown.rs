@@ -0,0 +1,189 @@
+//! Utilities for writing a report as
+//! Markdown, for pasting into issue trackers
+//! and READMEs.
+//!
+//! The report starts with a summary table, then a
+//! table of per-directory statistics if they were
+//! gathered, then the dates of each file listed by
+//! the report.
+//! Files with more than DETAILS_THRESHOLD dates
+//! have their list inside a collapsible `<details>`
+//! element, so long lists don't bury the rest of
+//! the report.
+
+///////////////
+// Constants //
+///////////////
+
+/// The amount of dates a file may have
+/// before its list is collapsed.
+pub const DETAILS_THRESHOLD : usize = 10;
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// A report written as Markdown with
+/// the Display trait.
+pub struct MarkdownReport<'l> {
+   report : &'l crate::report::FileAggregateReport<'l>,
+}
+
+//////////////////////////////
+// Methods - MarkdownReport //
+//////////////////////////////
+
+impl<'l> MarkdownReport<'l> {
+   /// Creates a new MarkdownReport from
+   /// an existing report.
+   pub fn from(
+      report   : &'l crate::report::FileAggregateReport<'l>,
+   ) -> Self {
+      return Self{
+         report : report,
+      };
+   }
+}
+
+////////////////////////////////////////////
+// Trait implementations - MarkdownReport //
+////////////////////////////////////////////
+
+impl<'l> std::fmt::Display for MarkdownReport<'l> {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      use crate::{
+         category::DateCategory,
+         date::Precision::*,
+      };
+
+      let report     = self.report;
+      let files      = report.raw_data();
+      let with_dates = files.iter().filter(|fd| fd.dates().is_empty() == false).count();
+      let dates      = files.iter().map(|fd| fd.dates().len()).sum::<usize>();
+
+      write!(stream, "# Dacom Report\n\n## Summary\n\n")?;
+      write!(stream, "| Statistic | Value |\n| --- | --- |\n")?;
+      write!(stream, "| Files searched | {} |\n", files.len())?;
+      write!(stream, "| Files with dates | {with_dates} |\n")?;
+      write!(stream, "| Dates found | {dates} |\n")?;
+      if let Some(category) = report.category() {
+         write!(stream, "| Compared by | {category} dates only |\n")?;
+      }
+      for (name, file) in [("Oldest", report.oldest()), ("Newest", report.newest()), ("Median", report.median())] {
+         write!(stream,
+            "| {name} file | {}{} |\n",
+            internal_code(file.path().to_str().unwrap_or("???")),
+            file.dates().first().map(|d| format!(" ({})", d.date())).unwrap_or_default(),
+         )?;
+      }
+      write!(stream,
+         "| Dates by precision | day {}, month {}, quarter {}, year {} |\n",
+         report.precision_count(Day),
+         report.precision_count(Month),
+         report.precision_count(Quarter),
+         report.precision_count(Year),
+      )?;
+      write!(stream,
+         "| Dates by category | {} |\n",
+         DateCategory::ALL.iter()
+            .map(|c| format!("{c} {}", report.category_count(*c)))
+            .collect::<Vec<_>>()
+            .join(", "),
+      )?;
+
+      if report.directories().is_empty() == false {
+         write!(stream, "\n## Directories\n\n")?;
+         write!(stream, "| Directory | Files | Dates | Earliest | Latest | Median |\n")?;
+         write!(stream, "| --- | ---: | ---: | --- | --- | --- |\n")?;
+         for directory in report.directories() {
+            write!(stream,
+               "| {} | {} | {} | {} | {} | {} |\n",
+               internal_code(directory.path().to_str().filter(|p| p.is_empty() == false).unwrap_or(".")),
+               directory.file_count(),
+               directory.date_count(),
+               directory.earliest(),
+               directory.latest(),
+               directory.median(),
+            )?;
+         }
+      }
+
+      let listed = report.listed_files();
+      if listed.is_empty() {
+         return Ok(());
+      }
+
+      write!(stream, "\n## Files\n")?;
+      for file in listed.into_iter().filter(|fd| fd.dates().is_empty() == false) {
+         let path  = file.path().to_str().unwrap_or("???");
+         let count = file.dates().len();
+         let plural = if count == 1 {""} else {"s"};
+
+         // Markdown isn't rendered inside of the summary
+         if count > DETAILS_THRESHOLD {
+            write!(stream,
+               "\n<details>\n<summary><code>{}</code> ({count} date{plural})</summary>\n\n",
+               crate::html::escape(path),
+            )?;
+         } else {
+            write!(stream, "\n{} ({count} date{plural})\n\n", internal_code(path))?;
+         }
+
+         for date in report.listed_dates(file) {
+            write!(stream, "- {}", date.date())?;
+            if let Some(author) = date.author() {
+               write!(stream, " by {}", internal_escape(author))?;
+            }
+            if date.category() != DateCategory::Other {
+               write!(stream, " ({})", date.category())?;
+            }
+            write!(stream, "\n")?;
+         }
+         let omitted = count - report.listed_dates(file).len();
+         if omitted > 0 {
+            write!(stream, "- *and {omitted} more*\n")?;
+         }
+
+         if count > DETAILS_THRESHOLD {
+            write!(stream, "\n</details>\n")?;
+         }
+      }
+
+      return Ok(());
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Writes text as an inline code span which
+/// is safe to put inside of a table cell.
+fn internal_code(
+   text  : & str,
+) -> String {
+   let text = text.replace('|', "\\|");
+   return match text.contains('`') {
+      true  => format!("`` {text} ``"),
+      false => format!("`{text}`"),
+   };
+}
+
+/// Escapes characters in plain text which
+/// Markdown would treat as formatting.
+fn internal_escape(
+   text  : & str,
+) -> String {
+   let mut escaped = String::with_capacity(text.len());
+   for c in text.chars() {
+      if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '#') {
+         escaped.push('\\');
+      }
+      escaped.push(c);
+   }
+
+   return escaped;
+}
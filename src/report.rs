@@ -12,21 +12,83 @@ pub enum ReportError {
    InvalidData,
 }
 
+/// The format a report is written in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+   /// Human-readable plain text.
+   Text,
+
+   /// Machine-readable JSON.
+   Json,
+
+   /// A self-contained HTML page with a
+   /// timeline, histogram, and table.
+   Html,
+
+   /// A static SVG chart.  See crate::svg
+   /// for more information.
+   Svg,
+
+   /// Markdown for issue trackers and
+   /// READMEs.
+   Markdown,
+}
+
+/// Error type detailing a parsing error
+/// for OutputFormat.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseOutputFormatError {
+   /// A format matching the input text
+   /// was not found.
+   NoMatchingFormat,
+}
+
 /// A type alias for the standard library
 /// result type with an error variant of
 /// ReportError.
 pub type Result<T> = std::result::Result<T, ReportError>;
 
+/// The activity of a single author,
+/// gathered from the dates attributed
+/// to them.
+pub struct AuthorActivity<'l> {
+   name     : &'l str,
+   first    : crate::date::Date,
+   last     : crate::date::Date,
+   files    : Vec<&'l std::path::Path>,
+   years    : Vec<(isize, usize)>,
+}
+
 /// Struct for containing the results
 /// of statistically analyzing a collection
 /// of files dates.
 pub struct FileAggregateReport<'l> {
-   raw_data : &'l crate::collect::FileAggregateDateList,
-   oldest   : &'l crate::collect::FileDateList,
-   newest   : &'l crate::collect::FileDateList,
-   median   : &'l crate::collect::FileDateList,
+   raw_data         : &'l crate::collect::FileAggregateDateList,
+   oldest           : &'l crate::collect::FileDateList,
+   newest           : &'l crate::collect::FileDateList,
+   median           : &'l crate::collect::FileDateList,
+   precision_counts : [usize; 4],
+   category_counts  : [usize; 4],
+   category         : Option<crate::category::DateCategory>,
+   authors          : Vec<AuthorActivity<'l>>,
+   directories      : Vec<crate::directory::DirectoryStats>,
+   svg_options      : crate::svg::SvgOptions,
+   heatmap          : Option<crate::heatmap::Heatmap>,
+   sort_order       : crate::sort::SortOrder,
+   reverse          : bool,
+   summary_only     : bool,
+   top              : Option<usize>,
+   date_limit       : Option<usize>,
 }
 
+///////////////
+// Constants //
+///////////////
+
+/// The maximum width of a bar in an
+/// activity histogram, in characters.
+pub const HISTOGRAM_WIDTH : usize = 40;
+
 /////////////////////////////////////////
 // Trait implementations - ReportError //
 /////////////////////////////////////////
@@ -34,6 +96,85 @@ pub struct FileAggregateReport<'l> {
 impl std::error::Error for ReportError {
 }
 
+////////////////////////////
+// Methods - OutputFormat //
+////////////////////////////
+
+impl OutputFormat {
+   /// A list of every output format.
+   pub const ALL : [Self; 5] = [
+      Self::Text,
+      Self::Json,
+      Self::Html,
+      Self::Svg,
+      Self::Markdown,
+   ];
+
+   /// Gets the name of the format as
+   /// used on the command line.
+   pub fn name(
+      & self,
+   ) -> &'static str {
+      return match self {
+         Self::Text     => "text",
+         Self::Json     => "json",
+         Self::Html     => "html",
+         Self::Svg      => "svg",
+         Self::Markdown => "markdown",
+      };
+   }
+}
+
+//////////////////////////////////////////
+// Trait implementations - OutputFormat //
+//////////////////////////////////////////
+
+impl std::default::Default for OutputFormat {
+   fn default(
+   ) -> Self {
+      return Self::Text;
+   }
+}
+
+impl std::fmt::Display for OutputFormat {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for OutputFormat {
+   type Err = ParseOutputFormatError;
+
+   fn from_str(
+      string : & str,
+   ) -> std::result::Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|f| f.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseOutputFormatError::NoMatchingFormat);
+   }
+}
+
+////////////////////////////////////////////////////
+// Trait implementations - ParseOutputFormatError //
+////////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseOutputFormatError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingFormat  => "No matching output format",
+      });
+   }
+}
+
+impl std::error::Error for ParseOutputFormatError {
+}
+
 impl std::fmt::Display for ReportError {
    fn fmt(
       & self,
@@ -60,31 +201,458 @@ impl<'l> FileAggregateReport<'l> {
    pub fn from(
       file_data   : &'l crate::collect::FileAggregateDateList,
    ) -> Result<Self> {
+      return Self::from_category(file_data, None);
+   }
+
+   /// Creates a new statistical report like
+   /// Self::from, but if a category is given,
+   /// files are compared using only their
+   /// oldest date in that category, such as
+   /// the date they were created.  Files
+   /// without a date in the category are left
+   /// out of the comparison.
+   pub fn from_category(
+      file_data   : &'l crate::collect::FileAggregateDateList,
+      category    : Option<crate::category::DateCategory>,
+   ) -> Result<Self> {
+      // Order the files by their oldest date in the category
+      let files = match category {
+         None           => file_data.iter().collect::<Vec<_>>(),
+         Some(category) => {
+            let mut files = file_data.iter()
+               .filter_map(|fd| fd.dates().iter()
+                  .find(|date| date.category() == category)
+                  .map(|date| (date.date(), fd))
+               )
+               .collect::<Vec<_>>();
+            files.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+            files.into_iter().map(|(_, fd)| fd).collect()
+         },
+      };
+
       // Get statistical variables
-      let oldest = match file_data.first() {
-         Some(fd) => fd,
+      let oldest = match files.first() {
+         Some(fd) => *fd,
          None     => return Err(ReportError::NoData),
       };
-      let newest = match file_data.last() {
-         Some(fd) => fd,
+      let newest = match files.last() {
+         Some(fd) => *fd,
          None     => return Err(ReportError::NoData),
       };
-      let median = match file_data.get(file_data.len() / 2) {
-         Some(fd) => fd,
+      let median = match files.get(files.len() / 2) {
+         Some(fd) => *fd,
          None     => return Err(ReportError::NoData),
       };
 
+      // Count how precisely each date is known
+      // and what each date describes
+      let mut precision_counts   = [0; 4];
+      let mut category_counts    = [0; 4];
+      for date in file_data.iter().flat_map(|fd| fd.dates().iter()) {
+         precision_counts[date.date().precision() as usize] += 1;
+         category_counts[date.category() as usize] += 1;
+      }
+
       // Create struct instance
       let report = Self{
-         raw_data : file_data,
-         oldest   : oldest,
-         newest   : newest,
-         median   : median,
+         raw_data         : file_data,
+         oldest           : oldest,
+         newest           : newest,
+         median           : median,
+         precision_counts : precision_counts,
+         category_counts  : category_counts,
+         category         : category,
+         authors          : AuthorActivity::from_files(file_data),
+         directories      : Vec::new(),
+         svg_options      : crate::svg::SvgOptions::default(),
+         heatmap          : None,
+         sort_order       : crate::sort::SortOrder::default(),
+         reverse          : false,
+         summary_only     : false,
+         top              : None,
+         date_limit       : None,
       };
 
       // Return success
       return Ok(report);
    }
+
+   /// Gets the data the report was
+   /// created from.
+   pub fn raw_data(
+      & self,
+   ) -> &'l crate::collect::FileAggregateDateList {
+      return self.raw_data;
+   }
+
+   /// Gets the file with the oldest date.
+   pub fn oldest(
+      & self,
+   ) -> &'l crate::collect::FileDateList {
+      return self.oldest;
+   }
+
+   /// Gets the file with the newest date.
+   pub fn newest(
+      & self,
+   ) -> &'l crate::collect::FileDateList {
+      return self.newest;
+   }
+
+   /// Gets the file with the median date.
+   pub fn median(
+      & self,
+   ) -> &'l crate::collect::FileDateList {
+      return self.median;
+   }
+
+   /// Gets the amount of dates found with
+   /// the given precision.
+   pub fn precision_count(
+      & self,
+      precision   : crate::date::Precision,
+   ) -> usize {
+      return self.precision_counts[precision as usize];
+   }
+
+   /// Gets the amount of dates found in
+   /// the given category.
+   pub fn category_count(
+      & self,
+      category    : crate::category::DateCategory,
+   ) -> usize {
+      return self.category_counts[category as usize];
+   }
+
+   /// Gets the category files are compared
+   /// by, if any.
+   pub fn category(
+      & self,
+   ) -> Option<crate::category::DateCategory> {
+      return self.category;
+   }
+
+   /// Creates a copy of the report with
+   /// per-directory statistics down to the
+   /// given depth below the common ancestor
+   /// of the files.  See
+   /// crate::directory::DirectoryStats::from_files
+   /// for more information.
+   pub fn with_directory_depth(
+      mut self,
+      depth       : usize,
+   ) -> Self {
+      self.directories = crate::directory::DirectoryStats::from_files(self.raw_data, depth);
+      return self;
+   }
+
+   /// Gets the per-directory statistics,
+   /// sorted by path.  This is empty unless
+   /// created with Self::with_directory_depth.
+   pub fn directories<'a>(
+      &'a self,
+   ) -> &'a [crate::directory::DirectoryStats] {
+      return &self.directories;
+   }
+
+   /// Creates a copy of the report with a
+   /// calendar heatmap of the dates found
+   /// within the given range of years.
+   pub fn with_heatmap(
+      mut self,
+      years       : std::ops::RangeInclusive<isize>,
+   ) -> Self {
+      self.heatmap = Some(crate::heatmap::Heatmap::from_files(self.raw_data, years));
+      return self;
+   }
+
+   /// Gets the calendar heatmap, if created
+   /// with Self::with_heatmap.
+   pub fn heatmap<'a>(
+      &'a self,
+   ) -> Option<&'a crate::heatmap::Heatmap> {
+      return self.heatmap.as_ref();
+   }
+
+   /// Creates a copy of the report which
+   /// lists files in the given order,
+   /// optionally reversed.
+   pub fn with_sort_order(
+      mut self,
+      order       : crate::sort::SortOrder,
+      reverse     : bool,
+   ) -> Self {
+      self.sort_order   = order;
+      self.reverse      = reverse;
+      return self;
+   }
+
+   /// Gets every file in the order chosen
+   /// with Self::with_sort_order, comparing
+   /// only their dates in the report's
+   /// category if any.
+   pub fn sorted_files(
+      & self,
+   ) -> Vec<&'l crate::collect::FileDateList> {
+      return self.raw_data.sorted_by(self.sort_order, self.reverse, self.category);
+   }
+
+   /// Creates a copy of the report which only
+   /// writes the summary, leaving out the near
+   /// misses, authors, edit history, and the
+   /// dates of every file.
+   pub fn with_summary_only(
+      mut self,
+      summary_only : bool,
+   ) -> Self {
+      self.summary_only = summary_only;
+      return self;
+   }
+
+   /// Creates a copy of the report which lists
+   /// only the given amount of oldest and newest
+   /// files with dates, instead of every file.
+   pub fn with_top(
+      mut self,
+      count       : usize,
+   ) -> Self {
+      self.top = Some(count);
+      return self;
+   }
+
+   /// Creates a copy of the report which lists
+   /// at most the given amount of dates for
+   /// each file.
+   pub fn with_date_limit(
+      mut self,
+      limit       : usize,
+   ) -> Self {
+      self.date_limit = Some(limit);
+      return self;
+   }
+
+   /// Gets the oldest and newest files with
+   /// dates in the report's category, or with
+   /// any dates if there is no category, if the
+   /// report was created with Self::with_top.
+   /// The newest files are listed newest first.
+   pub fn top_files(
+      & self,
+   ) -> Option<(Vec<&'l crate::collect::FileDateList>, Vec<&'l crate::collect::FileDateList>)> {
+      use crate::sort::SortOrder;
+
+      // Files are ordered the same as the oldest
+      // and newest file of the summary
+      let count = self.top?;
+      let top = |reverse : bool| self.raw_data.sorted_by(SortOrder::Oldest, reverse, self.category).into_iter()
+         .filter(|fd| fd.dates().iter().any(|d| self.category.is_none_or(|c| d.category() == c)))
+         .take(count)
+         .collect::<Vec<_>>();
+
+      return Some((top(false), top(true)));
+   }
+
+   /// Gets the files whose dates are listed in
+   /// the report.  This is every file in the
+   /// order chosen with Self::with_sort_order,
+   /// the oldest and newest files followed by
+   /// any remaining newest files if created
+   /// with Self::with_top, or no files if
+   /// created with Self::with_summary_only.
+   pub fn listed_files(
+      & self,
+   ) -> Vec<&'l crate::collect::FileDateList> {
+      if self.summary_only {
+         return Vec::new();
+      }
+
+      return match self.top_files() {
+         Some((mut oldest, newest)) => {
+            for file in newest {
+               if oldest.iter().all(|fd| std::ptr::eq(*fd, file) == false) {
+                  oldest.push(file);
+               }
+            }
+            oldest
+         },
+         None                       => self.sorted_files(),
+      };
+   }
+
+   /// Gets the dates of a file which are listed
+   /// in the report, limited by
+   /// Self::with_date_limit.
+   pub fn listed_dates<'a>(
+      & self,
+      file  : &'a crate::collect::FileDateList,
+   ) -> &'a [crate::collect::FoundDate] {
+      let dates = file.dates().as_slice();
+      return &dates[..dates.len().min(self.date_limit.unwrap_or(usize::MAX))];
+   }
+
+   /// Creates a copy of the report which
+   /// draws SVG charts with the given options.
+   pub fn with_svg_options(
+      mut self,
+      options     : crate::svg::SvgOptions,
+   ) -> Self {
+      self.svg_options = options;
+      return self;
+   }
+
+   /// Writes the report as machine-readable
+   /// JSON, containing the summary, directory
+   /// statistics, and every file's dates.
+   /// Dates are written as ISO 8601 text.
+   pub fn to_json(
+      & self,
+   ) -> String {
+      use serde_json::json;
+
+      let path = |path : & std::path::Path| path.to_string_lossy().into_owned();
+
+      let directories = self.directories.iter().map(|dir| json!({
+         "path"         : path(dir.path()),
+         "depth"        : dir.depth(),
+         "file_count"   : dir.file_count(),
+         "date_count"   : dir.date_count(),
+         "earliest"     : dir.earliest(),
+         "latest"       : dir.latest(),
+         "median"       : dir.median(),
+      })).collect::<Vec<_>>();
+      let files = self.listed_files().into_iter().map(|file| json!({
+         "path"         : path(file.path()),
+         "date_count"   : file.dates().len(),
+         "dates"        : self.listed_dates(file).iter().map(|date| date.date()).collect::<Vec<_>>(),
+      })).collect::<Vec<_>>();
+
+      let report = json!({
+         "oldest"       : path(self.oldest.path()),
+         "newest"       : path(self.newest.path()),
+         "median"       : path(self.median.path()),
+         "category"     : self.category.map(|c| c.name()),
+         "directories"  : directories,
+         "files"        : files,
+      });
+
+      return serde_json::to_string_pretty(&report).unwrap(); // Never fails for a JSON value
+   }
+
+   /// Writes the report in the given format.
+   /// Html pages and svg charts always show
+   /// every file and date, ignoring the top
+   /// files, summary only, date limit, and
+   /// category of the report.
+   pub fn to_format(
+      & self,
+      format      : OutputFormat,
+   ) -> String {
+      return match format {
+         OutputFormat::Text      => self.to_string(),
+         OutputFormat::Json      => self.to_json(),
+         OutputFormat::Html      => crate::html::HtmlReport::from(self.raw_data).to_string(),
+         OutputFormat::Svg       => crate::svg::SvgReport::new(self.raw_data, self.svg_options).to_string(),
+         OutputFormat::Markdown  => crate::markdown::MarkdownReport::from(self).to_string(),
+      };
+   }
+
+   /// Gets the activity of every author,
+   /// sorted by name.
+   pub fn authors<'a>(
+      &'a self,
+   ) -> &'a [AuthorActivity<'l>] {
+      return &self.authors;
+   }
+}
+
+//////////////////////////////
+// Methods - AuthorActivity //
+//////////////////////////////
+
+impl<'l> AuthorActivity<'l> {
+   /// Gathers the activity of every author
+   /// of the dates in a collection of files,
+   /// sorted by name.
+   pub fn from_files(
+      file_data   : &'l crate::collect::FileAggregateDateList,
+   ) -> Vec<Self> {
+      let mut authors = std::collections::BTreeMap::<&'l str, Self>::new();
+      for file in file_data.iter() {
+         for date in file.dates().iter() {
+            let name = match date.author() {
+               Some(a)  => a,
+               None     => continue,
+            };
+            let activity = authors.entry(name).or_insert_with(|| Self{
+               name     : name,
+               first    : date.date(),
+               last     : date.date(),
+               files    : Vec::new(),
+               years    : Vec::new(),
+            });
+
+            // Dates within a file are sorted, so
+            // only the last file needs checking
+            if activity.files.last() != Some(&file.path()) {
+               activity.files.push(file.path());
+            }
+            if date.date() < activity.first {
+               activity.first = date.date();
+            }
+            if date.date() > activity.last {
+               activity.last = date.date();
+            }
+
+            let year = date.date().year();
+            match activity.years.binary_search_by_key(&year, |(y, _)| *y) {
+               Ok(i)    => activity.years[i].1 += 1,
+               Err(i)   => activity.years.insert(i, (year, 1)),
+            }
+         }
+      }
+
+      return authors.into_values().collect();
+   }
+
+   /// Gets the author's name.
+   pub fn name(
+      & self,
+   ) -> &'l str {
+      return self.name;
+   }
+
+   /// Gets the oldest date attributed
+   /// to the author.
+   pub fn first(
+      & self,
+   ) -> crate::date::Date {
+      return self.first;
+   }
+
+   /// Gets the newest date attributed
+   /// to the author.
+   pub fn last(
+      & self,
+   ) -> crate::date::Date {
+      return self.last;
+   }
+
+   /// Gets the files the author touched,
+   /// in the order they were found.
+   pub fn files<'a>(
+      &'a self,
+   ) -> &'a [&'l std::path::Path] {
+      return &self.files;
+   }
+
+   /// Gets the amount of dates attributed
+   /// to the author in each year, sorted
+   /// by year.
+   pub fn years<'a>(
+      &'a self,
+   ) -> &'a [(isize, usize)] {
+      return &self.years;
+   }
 }
 
 /////////////////////////////////////////////////
@@ -96,37 +664,188 @@ impl<'l> std::fmt::Display for FileAggregateReport<'l> {
       & self,
       stream : & mut std::fmt::Formatter<'_>,
    ) -> std::fmt::Result {
+      use crate::{
+         category::DateCategory,
+         date::Precision::*,
+      };
+
       write!(stream, "--------- Data Summary ----------\n\n")?;
 
+      if let Some(category) = self.category {
+         write!(stream, "Comparing {category} dates only\n\n")?;
+      }
+
       write!(stream, "Oldest file:\n")?;
       write!(stream, "   {}\n", self.oldest.path().to_str().unwrap_or("???"))?;
-      for date in self.oldest.dates().iter() {
+      for date in self.listed_dates(self.oldest) {
          write!(stream, "   {date}\n")?;
       }
+      internal_write_omitted(stream, self.oldest, self.listed_dates(self.oldest))?;
 
       write!(stream, "\nNewest file:\n")?;
       write!(stream, "   {}\n", self.newest.path().to_str().unwrap_or("???"))?;
-      for date in self.newest.dates().iter() {
+      for date in self.listed_dates(self.newest) {
          write!(stream, "   {date}\n")?;
       }
+      internal_write_omitted(stream, self.newest, self.listed_dates(self.newest))?;
 
       write!(stream, "\nMedian file:\n")?;
       write!(stream, "   {}\n", self.median.path().to_str().unwrap_or("???"))?;
-      for date in self.median.dates().iter() {
+      for date in self.listed_dates(self.median) {
          write!(stream, "   {date}\n")?;
       }
+      internal_write_omitted(stream, self.median, self.listed_dates(self.median))?;
 
-      write!(stream, "\n----------- Raw Data ------------\n\n")?;
+      write!(stream, "\nDate precision:\n")?;
+      for precision in [Day, Month, Quarter, Year] {
+         write!(stream, "   {precision}: {}\n", self.precision_count(precision))?;
+      }
 
-      for file in self.raw_data.iter() {
-         write!(stream, "{}\n", file.path().to_str().unwrap_or("???"))?;
-         for date in file.dates().iter() {
-            write!(stream, "   {date}\n")?;
+      write!(stream, "\nDate categories:\n")?;
+      for category in DateCategory::ALL {
+         write!(stream, "   {category}: {}\n", self.category_count(category))?;
+      }
+
+      if self.directories.is_empty() == false {
+         write!(stream, "\n---------- Directories ----------\n\n")?;
+
+         for directory in self.directories.iter() {
+            write!(stream, "{directory}\n")?;
+         }
+      }
+
+      if let Some(heatmap) = &self.heatmap {
+         write!(stream, "\n------------ Heatmap ------------\n\n")?;
+         write!(stream, "{heatmap}")?;
+      }
+
+      if self.summary_only == false {
+         if self.raw_data.rejected().is_empty() == false {
+            write!(stream, "\n---------- Near Misses ----------\n")?;
+
+            for (path, rejected) in self.raw_data.rejected() {
+               write!(stream, "\n{}\n", path.to_str().unwrap_or("???"))?;
+               for rejection in rejected {
+                  write!(stream, "   {rejection}\n")?;
+               }
+            }
+         }
+
+         if self.authors.is_empty() == false {
+            write!(stream, "\n------------ Authors ------------\n")?;
+
+            for author in self.authors.iter() {
+               write!(stream, "\n{}\n", author.name())?;
+               write!(stream, "   Active: {} to {}\n", author.first(), author.last())?;
+               write!(stream, "   Files:\n")?;
+               for path in author.files() {
+                  write!(stream, "      {}\n", path.to_str().unwrap_or("???"))?;
+               }
+               write!(stream, "   Activity:\n")?;
+               let max = author.years().iter().map(|(_, c)| *c).max().unwrap_or(1).max(HISTOGRAM_WIDTH);
+               for (year, count) in author.years() {
+                  let width = (count * HISTOGRAM_WIDTH).div_ceil(max);
+                  write!(stream, "      {year:>4} {} {count}\n", "#".repeat(width))?;
+               }
+            }
+         }
+
+         if self.raw_data.iter().any(|fd| fd.changelog().is_empty() == false) {
+            write!(stream, "\n--------- Edit History ----------\n")?;
+
+            for file in self.raw_data.iter().filter(|fd| fd.changelog().is_empty() == false) {
+               let mut entries = file.changelog().iter().collect::<Vec<_>>();
+               entries.sort_by(|a, b| a.date().partial_cmp(&b.date()).unwrap_or(std::cmp::Ordering::Equal));
+
+               write!(stream, "\n{}\n", file.path().to_str().unwrap_or("???"))?;
+               for entry in entries {
+                  write!(stream, "   {entry}\n")?;
+               }
+            }
+         }
+
+         match self.top_files() {
+            Some((oldest, newest)) => {
+               write!(stream, "\n--------- Oldest Files ----------\n\n")?;
+               for file in oldest {
+                  self.internal_write_file(stream, file)?;
+               }
+
+               write!(stream, "--------- Newest Files ----------\n\n")?;
+               for file in newest {
+                  self.internal_write_file(stream, file)?;
+               }
+            },
+            None                   => {
+               write!(stream, "\n----------- Raw Data ------------\n\n")?;
+               for file in self.sorted_files() {
+                  self.internal_write_file(stream, file)?;
+               }
+            },
          }
-         write!(stream, "\n")?;
       }
 
       return Ok(());
    }
 }
 
+////////////////////////////////////////////
+// Internal helpers - FileAggregateReport //
+////////////////////////////////////////////
+
+impl<'l> FileAggregateReport<'l> {
+   /// Writes the path and listed dates of a
+   /// file, with everything known about each
+   /// date.
+   fn internal_write_file(
+      & self,
+      stream   : & mut std::fmt::Formatter<'_>,
+      file     : & crate::collect::FileDateList,
+   ) -> std::fmt::Result {
+      use crate::category::DateCategory;
+
+      write!(stream, "{}\n", file.path().to_str().unwrap_or("???"))?;
+      for date in self.listed_dates(file) {
+         write!(stream, "   {date}")?;
+         if let Some(offset) = date.offset() {
+            write!(stream, " (offset {offset:#x})")?;
+         }
+         if let Some((first, last)) = date.range() {
+            write!(stream, " (range {first} to {last})")?;
+         }
+         if date.is_fuzzy() {
+            write!(stream, " (fuzzy)")?;
+         }
+         if let Some(author) = date.author() {
+            write!(stream, " (by {author})")?;
+         }
+         if date.category() != DateCategory::Other {
+            write!(stream, " ({})", date.category())?;
+         }
+         write!(stream, " (confidence {:.2})", date.confidence())?;
+         write!(stream, "\n")?;
+      }
+      internal_write_omitted(stream, file, self.listed_dates(file))?;
+
+      return write!(stream, "\n");
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Writes how many dates of a file were
+/// left out of the listed dates, if any.
+fn internal_write_omitted(
+   stream   : & mut std::fmt::Formatter<'_>,
+   file     : & crate::collect::FileDateList,
+   listed   : & [crate::collect::FoundDate],
+) -> std::fmt::Result {
+   let omitted = file.dates().len() - listed.len();
+   if omitted > 0 {
+      write!(stream, "   ... and {omitted} more date{}\n", if omitted == 1 {""} else {"s"})?;
+   }
+
+   return Ok(());
+}
@@ -0,0 +1,189 @@
+//! Utilities for saving the dates found in a
+//! search to a file and loading them back.
+//!
+//! A saved scan is JSON containing every file
+//! with dates, along with everything known about
+//! each date.  Loading a scan recreates the
+//! FileAggregateDateList it was saved from, so
+//! reports can be written without searching the
+//! files again and two scans of the same tree
+//! can be compared.  See crate::diff for more
+//! information.  Dates are saved as ISO 8601
+//! text along with the calendar and time zone
+//! abbreviation they were written in, which ISO
+//! 8601 can't store.  Near misses and changelog
+//! entries aren't saved.
+
+///////////////
+// Constants //
+///////////////
+
+/// The version of the saved scan format.
+/// Scans saved with a different version
+/// can't be loaded.
+pub const SCAN_VERSION : usize = 1;
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// Error type detailing a parsing error
+/// for a saved scan.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseScanError {
+   /// The text isn't a saved scan.
+   InvalidFormatting,
+
+   /// The scan was saved with a different
+   /// version of the format.
+   UnsupportedVersion,
+}
+
+/// A saved scan as written to a file.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ScanFile {
+   version  : usize,
+   files    : Vec<ScanEntry>,
+}
+
+/// A single file within a saved scan.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ScanEntry {
+   path     : String,
+   dates    : Vec<ScanDate>,
+}
+
+/// A single date within a saved scan.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ScanDate {
+   date        : crate::date::Date,
+   calendar    : String,
+   zone        : Option<String>,
+   offset      : Option<usize>,
+   range       : Option<(crate::date::Date, crate::date::Date)>,
+   fuzzy       : bool,
+   confidence  : f64,
+   category    : String,
+   author      : Option<String>,
+   length      : usize,
+}
+
+////////////////////////////////////////////
+// Trait implementations - ParseScanError //
+////////////////////////////////////////////
+
+impl std::fmt::Display for ParseScanError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::InvalidFormatting    => "Text is not a saved scan",
+         Self::UnsupportedVersion   => "Scan was saved with an unsupported version",
+      });
+   }
+}
+
+impl std::error::Error for ParseScanError {
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Saves every file and its dates as
+/// JSON text.
+pub fn to_json(
+   file_data   : & crate::collect::FileAggregateDateList,
+) -> String {
+   let files = file_data.iter().map(|file| ScanEntry{
+      path     : file.path().to_string_lossy().into_owned(),
+      dates    : file.dates().iter().map(|date| ScanDate{
+         date        : date.date(),
+         calendar    : date.date().calendar().name().to_string(),
+         zone        : date.date().zone().and_then(|z| z.abbreviation()).map(str::to_string),
+         offset      : date.offset(),
+         range       : date.range(),
+         fuzzy       : date.is_fuzzy(),
+         confidence  : date.confidence(),
+         category    : date.category().name().to_string(),
+         author      : date.author().map(str::to_string),
+         length      : date.length(),
+      }).collect(),
+   }).collect();
+
+   let scan = ScanFile{
+      version  : SCAN_VERSION,
+      files    : files,
+   };
+
+   return serde_json::to_string_pretty(&scan).unwrap(); // Never fails for plain data
+}
+
+/// Loads the files and their dates from
+/// JSON text written by to_json.
+pub fn from_json(
+   text  : & str,
+) -> Result<crate::collect::FileAggregateDateList, ParseScanError> {
+   use crate::collect::{DateList, FileDateList, FoundDate};
+
+   let scan = serde_json::from_str::<ScanFile>(text)
+      .map_err(|_| ParseScanError::InvalidFormatting)?;
+   if scan.version != SCAN_VERSION {
+      return Err(ParseScanError::UnsupportedVersion);
+   }
+
+   let mut files = Vec::with_capacity(scan.files.len());
+   for entry in scan.files {
+      let mut dates = Vec::with_capacity(entry.dates.len());
+      for date in entry.dates {
+         let category = date.category.parse()
+            .map_err(|_| ParseScanError::InvalidFormatting)?;
+         let calendar = date.calendar.parse()
+            .map_err(|_| ParseScanError::InvalidFormatting)?;
+         let zone = match date.zone {
+            Some(zone)  => Some(zone.parse().map_err(|_| ParseScanError::InvalidFormatting)?),
+            None        => None,
+         };
+         let restore = |date| internal_restore_date(date, calendar, zone);
+
+         let mut found = FoundDate::new(restore(date.date), date.offset)
+            .with_fuzzy(date.fuzzy)
+            .with_confidence(date.confidence)
+            .with_category(category)
+            .with_author(date.author)
+            .with_length(date.length);
+         if let Some((first, last)) = date.range {
+            found = found.with_range(restore(first), restore(last));
+         }
+         dates.push(found);
+      }
+
+      files.push(FileDateList::from(
+         std::path::PathBuf::from(entry.path),
+         DateList::from_found(sorted_vec::SortedVec::from_unsorted(dates)),
+      ));
+   }
+
+   return Ok(files.into_iter().collect());
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Restores the calendar and time zone
+/// abbreviation of a date loaded from ISO
+/// 8601 text.  The abbreviation is only
+/// restored if its offset matches the date.
+fn internal_restore_date(
+   date     : crate::date::Date,
+   calendar : crate::date::Calendar,
+   zone     : Option<crate::date::TimeZone>,
+) -> crate::date::Date {
+   let date = date.to_calendar(calendar);
+   return match (date.zone(), zone) {
+      (Some(offset), Some(zone)) if offset.offset() == zone.offset() => date.with_zone(zone),
+      _ => date,
+   };
+}
@@ -0,0 +1,195 @@
+//! Utilities for choosing the order files
+//! are listed in.
+//!
+//! By default files are listed in the order of
+//! their Ord implementation, which compares their
+//! dates from oldest to newest and then their paths.
+//! A SortOrder chooses a different key, and any
+//! order can be reversed.  Ties are always broken
+//! by the default order, so the listing is stable
+//! between runs.  Files may be compared using only
+//! their dates in a single category, such as the
+//! date they were created, where files without a
+//! date in the category are treated as having no
+//! dates.
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// The order to list files in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+   /// By the oldest date, oldest first.
+   /// This is the default order.
+   Oldest,
+
+   /// By the newest date, oldest first.
+   Newest,
+
+   /// By path.
+   Path,
+
+   /// By the amount of dates, most first.
+   Count,
+
+   /// By the length of time between the
+   /// oldest and newest date, longest first.
+   Span,
+
+   /// By the newest date, newest first.
+   LatestFirst,
+}
+
+/// Error type detailing a parsing error
+/// for SortOrder.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseSortOrderError {
+   /// An order matching the input text
+   /// was not found.
+   NoMatchingOrder,
+}
+
+/////////////////////////
+// Methods - SortOrder //
+/////////////////////////
+
+impl SortOrder {
+   /// A list of every sort order.
+   pub const ALL : [Self; 6] = [
+      Self::Oldest,
+      Self::Newest,
+      Self::Path,
+      Self::Count,
+      Self::Span,
+      Self::LatestFirst,
+   ];
+
+   /// Gets the name of the order as
+   /// used on the command line.
+   pub fn name(
+      & self,
+   ) -> &'static str {
+      return match self {
+         Self::Oldest      => "oldest",
+         Self::Newest      => "newest",
+         Self::Path        => "path",
+         Self::Count       => "count",
+         Self::Span        => "span",
+         Self::LatestFirst => "latest-first",
+      };
+   }
+
+   /// Compares two files by the order, using
+   /// only their dates in the given category
+   /// if any.
+   pub fn compare(
+      & self,
+      a        : & crate::collect::FileDateList,
+      b        : & crate::collect::FileDateList,
+      category : Option<crate::category::DateCategory>,
+   ) -> std::cmp::Ordering {
+      let (a_first, a_last, a_count) = internal_key(a, category);
+      let (b_first, b_last, b_count) = internal_key(b, category);
+      let span = |first : Option<crate::date::Date>, last : Option<crate::date::Date>| match (first, last) {
+         (Some(first), Some(last))  => last.days_since_epoch().saturating_sub(first.days_since_epoch()),
+         _                          => 0,
+      };
+
+      let order = match self {
+         Self::Oldest      => a_first.cmp(&b_first).then_with(|| a_last.cmp(&b_last)),
+         Self::Newest      => a_last.cmp(&b_last),
+         Self::Path        => a.path().cmp(b.path()),
+         Self::Count       => b_count.cmp(&a_count),
+         Self::Span        => span(b_first, b_last).cmp(&span(a_first, a_last)),
+         Self::LatestFirst => b_last.cmp(&a_last),
+      };
+
+      return order.then_with(|| a.cmp(b));
+   }
+
+   /// Sorts a list of files by the order,
+   /// optionally reversed, using only their
+   /// dates in the given category if any.
+   pub fn sort(
+      & self,
+      files    : & mut [& crate::collect::FileDateList],
+      reverse  : bool,
+      category : Option<crate::category::DateCategory>,
+   ) {
+      files.sort_by(|a, b| match reverse {
+         false => self.compare(a, b, category),
+         true  => self.compare(b, a, category),
+      });
+      return;
+   }
+}
+
+///////////////////////////////////////
+// Trait implementations - SortOrder //
+///////////////////////////////////////
+
+impl std::default::Default for SortOrder {
+   fn default(
+   ) -> Self {
+      return Self::Oldest;
+   }
+}
+
+impl std::fmt::Display for SortOrder {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for SortOrder {
+   type Err = ParseSortOrderError;
+
+   fn from_str(
+      string : & str,
+   ) -> Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|o| o.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseSortOrderError::NoMatchingOrder);
+   }
+}
+
+/////////////////////////////////////////////////
+// Trait implementations - ParseSortOrderError //
+/////////////////////////////////////////////////
+
+impl std::fmt::Display for ParseSortOrderError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingOrder => "No matching sort order",
+      });
+   }
+}
+
+impl std::error::Error for ParseSortOrderError {
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Gets the oldest date, newest date, and
+/// amount of dates of a file, counting only
+/// the dates in the given category if any.
+fn internal_key(
+   file     : & crate::collect::FileDateList,
+   category : Option<crate::category::DateCategory>,
+) -> (Option<crate::date::Date>, Option<crate::date::Date>, usize) {
+   let dates = file.dates().iter()
+      .filter(|d| category.is_none_or(|c| d.category() == c))
+      .map(|d| d.date())
+      .collect::<Vec<_>>();
+
+   return (dates.first().copied(), dates.last().copied(), dates.len());
+}
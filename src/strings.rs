@@ -0,0 +1,190 @@
+//! Utilities for extracting printable strings
+//! from binary data.
+//!
+//! This works similarly to the "strings" utility,
+//! searching for runs of printable ASCII characters
+//! encoded either as single bytes or as UTF-16LE.
+//! Dates embedded in compiled programs, such as
+//! those created by the C __DATE__ macro, can then
+//! be searched for within the found strings.
+
+///////////////
+// Constants //
+///////////////
+
+/// The default minimum amount of characters
+/// in a run for it to be considered a string.
+pub const DEFAULT_MIN_LENGTH : usize = 4;
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// The encoding a string was found in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StringEncoding {
+   /// One byte per character.
+   Ascii,
+
+   /// Two bytes per character, little-endian.
+   Utf16Le,
+}
+
+/// A run of printable characters found
+/// within binary data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrintableString {
+   offset   : usize,
+   encoding : StringEncoding,
+   text     : String,
+}
+
+//////////////////////////////
+// Methods - StringEncoding //
+//////////////////////////////
+
+impl StringEncoding {
+   /// Gets the amount of bytes used to
+   /// store a single character.
+   pub fn char_width(
+      & self,
+   ) -> usize {
+      return match self {
+         Self::Ascii    => 1,
+         Self::Utf16Le  => 2,
+      };
+   }
+}
+
+///////////////////////////////
+// Methods - PrintableString //
+///////////////////////////////
+
+impl PrintableString {
+   /// Gets the byte offset of the start
+   /// of the string within the data.
+   pub fn offset(
+      & self,
+   ) -> usize {
+      return self.offset;
+   }
+
+   /// Gets the encoding the string was
+   /// found in.
+   pub fn encoding(
+      & self,
+   ) -> StringEncoding {
+      return self.encoding;
+   }
+
+   /// Gets the string's text, converted
+   /// to UTF-8.
+   pub fn text<'l>(
+      &'l self,
+   ) -> &'l str {
+      return &self.text;
+   }
+
+   /// Converts a byte offset within the
+   /// string's text into a byte offset
+   /// within the original data.
+   pub fn data_offset(
+      & self,
+      text_offset : usize,
+   ) -> usize {
+      return self.offset + text_offset * self.encoding.char_width();
+   }
+}
+
+///////////////
+// Functions //
+///////////////
+
+/// Extracts every run of at least the given
+/// amount of printable characters from binary
+/// data, in both ASCII and UTF-16LE encodings.
+/// Strings are returned in the order they appear
+/// within the data.
+pub fn extract(
+   data        : & [u8],
+   min_length  : usize,
+) -> Vec<PrintableString> {
+   let mut strings = Vec::new();
+
+   internal_extract_encoding(data, min_length, StringEncoding::Ascii,   & mut strings);
+   internal_extract_encoding(data, min_length, StringEncoding::Utf16Le, & mut strings);
+
+   strings.sort_by_key(|s| s.offset);
+   return strings;
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Checks whether a byte is a printable
+/// ASCII character or a tab.
+fn internal_is_printable(
+   byte : u8,
+) -> bool {
+   return byte == b'\t' || (0x20..=0x7E).contains(&byte);
+}
+
+/// Extracts every string in a single encoding.
+/// UTF-16LE strings are searched for at both
+/// even and odd byte alignments.
+fn internal_extract_encoding(
+   data        : & [u8],
+   min_length  : usize,
+   encoding    : StringEncoding,
+   strings     : & mut Vec<PrintableString>,
+) {
+   let width = encoding.char_width();
+
+   for alignment in 0..width {
+      let mut start = alignment;
+      let mut text  = String::new();
+
+      let mut index = alignment;
+      while index + width <= data.len() {
+         let chunk = &data[index..index + width];
+         let is_char = internal_is_printable(chunk[0])
+            && chunk[1..].iter().all(|b| *b == 0);
+
+         if is_char {
+            if text.is_empty() {
+               start = index;
+            }
+            text.push(chunk[0] as char);
+         } else {
+            internal_push_string(start, encoding, & mut text, min_length, strings);
+         }
+
+         index += width;
+      }
+      internal_push_string(start, encoding, & mut text, min_length, strings);
+   }
+
+   return;
+}
+
+/// Moves a run of characters into the string
+/// list if it is long enough, clearing the run.
+fn internal_push_string(
+   offset      : usize,
+   encoding    : StringEncoding,
+   text        : & mut String,
+   min_length  : usize,
+   strings     : & mut Vec<PrintableString>,
+) {
+   if text.len() >= min_length.max(1) {
+      strings.push(PrintableString{
+         offset   : offset,
+         encoding : encoding,
+         text     : std::mem::take(text),
+      });
+   }
+   text.clear();
+
+   return;
+}
@@ -0,0 +1,424 @@
+//! Utilities for drawing the dates found in
+//! files as static SVG charts.
+//!
+//! Unlike crate::html, the charts have no styling
+//! or scripts beyond plain SVG attributes, so they
+//! can be embedded as images in wikis and documents.
+//! Two charts are available: a histogram of the
+//! dates found per month, and a Gantt chart with a
+//! bar for the span between each file's first and
+//! last date.
+
+///////////////
+// Constants //
+///////////////
+
+/// The default width of a chart, in pixels.
+pub const DEFAULT_WIDTH : usize = 800;
+
+/// The default height of a chart, in pixels.
+pub const DEFAULT_HEIGHT : usize = 400;
+
+/// The space around the plotting area for
+/// axis labels, in pixels.
+const MARGIN : usize = 30;
+
+/// The minimum horizontal space between
+/// labels on the time axis, in pixels.
+const LABEL_SPACING : f64 = 48.0;
+
+/// The approximate width of a character
+/// of label text, in pixels.
+const CHARACTER_WIDTH : f64 = 6.5;
+
+/// The color of bars in every chart.
+const BAR_COLOR : &str = "#3a7bd5";
+
+/////////////////////////////////
+// Struct and enum definitions //
+/////////////////////////////////
+
+/// Which chart to draw.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SvgChart {
+   /// A histogram of dates per month.
+   Histogram,
+
+   /// A bar for the span between each
+   /// file's first and last date.
+   Gantt,
+}
+
+/// Error type detailing a parsing error
+/// for SvgChart.
+#[derive(Copy, Clone, Debug)]
+pub enum ParseSvgError {
+   /// A chart matching the input text
+   /// was not found.
+   NoMatchingChart,
+}
+
+/// Options controlling how a chart is drawn.
+/// The default options draw a Gantt chart of
+/// DEFAULT_WIDTH by DEFAULT_HEIGHT pixels with
+/// files in the default sort order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SvgOptions {
+   /// The chart to draw.
+   pub chart   : SvgChart,
+
+   /// The width of the chart, in pixels.
+   pub width   : usize,
+
+   /// The height of the chart, in pixels.
+   /// Bars in a Gantt chart share the height
+   /// between every file.
+   pub height  : usize,
+
+   /// The order of the files in a Gantt
+   /// chart, from top to bottom.
+   pub order   : crate::sort::SortOrder,
+
+   /// Whether to reverse the order of the
+   /// files in a Gantt chart.
+   pub reverse : bool,
+}
+
+/// A static SVG chart of the dates found in
+/// a set of files, written with the Display
+/// trait.
+pub struct SvgReport<'l> {
+   raw_data : &'l crate::collect::FileAggregateDateList,
+   options  : SvgOptions,
+}
+
+////////////////////////
+// Methods - SvgChart //
+////////////////////////
+
+impl SvgChart {
+   /// A list of every chart.
+   pub const ALL : [Self; 2] = [
+      Self::Histogram,
+      Self::Gantt,
+   ];
+
+   /// Gets the name of the chart as
+   /// used on the command line.
+   pub fn name(
+      & self,
+   ) -> &'static str {
+      return match self {
+         Self::Histogram   => "histogram",
+         Self::Gantt       => "gantt",
+      };
+   }
+}
+
+//////////////////////////////////////
+// Trait implementations - SvgChart //
+//////////////////////////////////////
+
+impl std::fmt::Display for SvgChart {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", self.name());
+   }
+}
+
+impl std::str::FromStr for SvgChart {
+   type Err = ParseSvgError;
+
+   fn from_str(
+      string : & str,
+   ) -> Result<Self, Self::Err> {
+      return Self::ALL.into_iter()
+         .find(|c| c.name().eq_ignore_ascii_case(string))
+         .ok_or(ParseSvgError::NoMatchingChart);
+   }
+}
+
+///////////////////////////////////////////
+// Trait implementations - ParseSvgError //
+///////////////////////////////////////////
+
+impl std::fmt::Display for ParseSvgError {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      return write!(stream, "{}", match self {
+         Self::NoMatchingChart  => "No matching chart",
+      });
+   }
+}
+
+impl std::error::Error for ParseSvgError {
+}
+
+////////////////////////////////////////
+// Trait implementations - SvgOptions //
+////////////////////////////////////////
+
+impl std::default::Default for SvgOptions {
+   fn default(
+   ) -> Self {
+      return Self{
+         chart    : SvgChart::Gantt,
+         width    : DEFAULT_WIDTH,
+         height   : DEFAULT_HEIGHT,
+         order    : crate::sort::SortOrder::default(),
+         reverse  : false,
+      };
+   }
+}
+
+/////////////////////////
+// Methods - SvgReport //
+/////////////////////////
+
+impl<'l> SvgReport<'l> {
+   /// Creates a new SvgReport for the dates
+   /// found in a set of files.
+   pub fn new(
+      file_data   : &'l crate::collect::FileAggregateDateList,
+      options     : SvgOptions,
+   ) -> Self {
+      return Self{
+         raw_data : file_data,
+         options  : options,
+      };
+   }
+
+   /// Gets the options the chart is
+   /// drawn with.
+   pub fn options(
+      & self,
+   ) -> SvgOptions {
+      return self.options;
+   }
+}
+
+///////////////////////////////////////
+// Trait implementations - SvgReport //
+///////////////////////////////////////
+
+impl<'l> std::fmt::Display for SvgReport<'l> {
+   fn fmt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      write!(stream,
+         "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" \
+         font-family=\"sans-serif\" font-size=\"11\">\n",
+         w = self.options.width,
+         h = self.options.height,
+      )?;
+      write!(stream,
+         "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+         self.options.width,
+         self.options.height,
+      )?;
+
+      match self.options.chart {
+         SvgChart::Histogram  => self.internal_write_histogram(stream)?,
+         SvgChart::Gantt      => self.internal_write_gantt(stream)?,
+      }
+
+      return write!(stream, "</svg>\n");
+   }
+}
+
+//////////////////////////////////
+// Internal helpers - SvgReport //
+//////////////////////////////////
+
+impl<'l> SvgReport<'l> {
+   /// Draws a bar for every month between the
+   /// first and last month with dates.  Dates
+   /// only known to the year or quarter aren't
+   /// counted, since their month is unknown.
+   fn internal_write_histogram(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      use crate::date::{Date, Month, Precision};
+
+      // Count the dates found in each month
+      let mut months = std::collections::BTreeMap::<isize, usize>::new();
+      for file in self.raw_data.iter() {
+         for date in file.dates().iter().map(|d| d.date()) {
+            if date.precision() >= Precision::Month {
+               *months.entry(date.year() * 12 + date.month() as isize).or_insert(0) += 1;
+            }
+         }
+      }
+
+      let (first, last) = match (months.keys().next(), months.keys().next_back()) {
+         (Some(first), Some(last))  => (*first, *last),
+         _                          => return Ok(()),
+      };
+      let max     = months.values().copied().max().unwrap_or(1);
+      let count   = (last - first + 1) as usize;
+      let left    = MARGIN as f64;
+      let bottom  = self.options.height.saturating_sub(MARGIN) as f64;
+      let plot_w  = self.options.width.saturating_sub(MARGIN * 2) as f64;
+      let plot_h  = (bottom - MARGIN as f64).max(0.0);
+      let bar_w   = plot_w / count as f64;
+
+      // Bars, with gaps only if there's room
+      let gap = if bar_w >= 4.0 {1.0} else {0.0};
+      for (&month, &amount) in months.iter() {
+         let index  = month - first;
+         let height = amount as f64 / max as f64 * plot_h;
+         let date   = Date::new_month(Month::ALL[month.rem_euclid(12) as usize], month.div_euclid(12));
+         write!(stream,
+            "<rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"{BAR_COLOR}\">\
+            <title>{}: {amount} date{}</title></rect>\n",
+            left + index as f64 * bar_w + gap / 2.0,
+            bottom - height,
+            (bar_w - gap).max(0.5),
+            height,
+            crate::html::escape(&date.to_string()),
+            if amount == 1 {""} else {"s"},
+         )?;
+      }
+
+      // Axes, labelled with the maximum count and years
+      write!(stream,
+         "<line x1=\"{left}\" y1=\"{bottom}\" x2=\"{:.2}\" y2=\"{bottom}\" stroke=\"#999\"/>\n",
+         left + plot_w,
+      )?;
+      write!(stream,
+         "<text x=\"{:.2}\" y=\"{}\" text-anchor=\"end\">{max}</text>\n",
+         left - 4.0,
+         MARGIN + 4,
+      )?;
+
+      let step = internal_label_step(12.0 * bar_w);
+      let mut year = first.div_euclid(12).div_euclid(step) * step;
+      while year * 12 <= last {
+         if year * 12 >= first {
+            write!(stream,
+               "<text x=\"{:.2}\" y=\"{:.2}\">{}</text>\n",
+               left + (year * 12 - first) as f64 * bar_w,
+               bottom + 14.0,
+               crate::html::escape(&Date::new_year(year).to_string()),
+            )?;
+         }
+         year += step;
+      }
+
+      return Ok(());
+   }
+
+   /// Draws a bar for the span between each
+   /// file's first and last date, labelled with
+   /// the path of the file.
+   fn internal_write_gantt(
+      & self,
+      stream : & mut std::fmt::Formatter<'_>,
+   ) -> std::fmt::Result {
+      use crate::date::Date;
+
+      let files = self.raw_data.sorted_by(self.options.order, self.options.reverse, None).into_iter()
+         .filter_map(|fd| {
+            let first = fd.dates().first()?.date();
+            let last  = fd.dates().last()?.date();
+            return Some((fd.path(), first, last, first.days_since_epoch(), last.days_since_epoch()));
+         })
+         .collect::<Vec<_>>();
+
+      let earliest = match files.iter().map(|(_, _, _, first, _)| *first).min() {
+         Some(earliest) => earliest,
+         None           => return Ok(()),
+      };
+      let latest  = files.iter().map(|(_, _, _, _, last)| *last).max().unwrap_or(earliest);
+      let span    = (latest - earliest).max(1) as f64;
+      let label_w = (self.options.width as f64 * 0.3).floor();
+      let left    = label_w + 4.0;
+      let plot_w  = (self.options.width as f64 - left - MARGIN as f64).max(0.0);
+      let top     = MARGIN as f64 / 2.0;
+      let bottom  = self.options.height.saturating_sub(MARGIN) as f64;
+      let row_h   = ((bottom - top) / files.len() as f64).max(0.0);
+      let x_of    = |days : i64| left + (days - earliest) as f64 / span * plot_w;
+
+      // Time axis, labelled with years
+      write!(stream,
+         "<line x1=\"{left:.2}\" y1=\"{bottom}\" x2=\"{:.2}\" y2=\"{bottom}\" stroke=\"#999\"/>\n",
+         left + plot_w,
+      )?;
+
+      let first_year = Date::from_days_since_epoch(earliest).year();
+      let last_year  = Date::from_days_since_epoch(latest).year();
+      let step       = internal_label_step(365.25 / span * plot_w);
+      let mut year = first_year.div_euclid(step) * step;
+      while year <= last_year {
+         let start = Date::new_year(year).days_since_epoch();
+         if start >= earliest {
+            write!(stream,
+               "<line x1=\"{x:.2}\" y1=\"{top}\" x2=\"{x:.2}\" y2=\"{bottom}\" stroke=\"#ddd\"/>\n\
+               <text x=\"{x:.2}\" y=\"{:.2}\" text-anchor=\"middle\">{}</text>\n",
+               bottom + 14.0,
+               crate::html::escape(&Date::new_year(year).to_string()),
+               x = x_of(start),
+            )?;
+         }
+         year += step;
+      }
+
+      // Rows of labelled bars
+      let max_chars = ((label_w - 4.0) / CHARACTER_WIDTH).max(1.0) as usize;
+      for (index, (path, first, last, first_days, last_days)) in files.iter().enumerate() {
+         let path  = path.to_str().unwrap_or("???");
+         let count = path.chars().count();
+         let label = match count > max_chars {
+            true  => format!("\u{2026}{}", path.chars().skip(count + 1 - max_chars).collect::<String>()),
+            false => path.to_string(),
+         };
+         let y = top + index as f64 * row_h;
+
+         write!(stream,
+            "<text x=\"{label_w:.2}\" y=\"{:.2}\" text-anchor=\"end\" dominant-baseline=\"middle\">{}</text>\n",
+            y + row_h / 2.0,
+            crate::html::escape(&label),
+         )?;
+         write!(stream,
+            "<rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\" fill=\"{BAR_COLOR}\">\
+            <title>{}: {} to {}</title></rect>\n",
+            x_of(*first_days),
+            y + row_h * 0.15,
+            (x_of(*last_days) - x_of(*first_days)).max(2.0),
+            row_h * 0.7,
+            crate::html::escape(path),
+            crate::html::escape(&first.to_string()),
+            crate::html::escape(&last.to_string()),
+         )?;
+      }
+
+      return Ok(());
+   }
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Finds how many years apart labels on a
+/// time axis must be so they don't overlap,
+/// given the width of a year in pixels.
+fn internal_label_step(
+   year_width  : f64,
+) -> isize {
+   let mut step = 1;
+   while (step as f64) * year_width < LABEL_SPACING && step < isize::MAX / 10 {
+      step = match step.to_string().starts_with('2') {
+         true  => step / 2 * 5,
+         false => step * 2,
+      };
+   }
+
+   return step;
+}
@@ -0,0 +1,181 @@
+//! Unit tests for crate::archive.
+
+/// Creates an in-memory tar archive
+/// from a list of names and contents.
+fn create_tar(
+   members : & [(&str, &[u8])],
+) -> Vec<u8> {
+   let mut builder = tar::Builder::new(Vec::new());
+   for (name, data) in members {
+      let mut header = tar::Header::new_gnu();
+      header.set_size(data.len() as u64);
+      header.set_mode(0o644);
+      header.set_cksum();
+      builder.append_data(& mut header, name, *data).unwrap();
+   }
+
+   return builder.into_inner().unwrap();
+}
+
+/// Creates an in-memory zip archive
+/// from a list of names and contents.
+fn create_zip(
+   members : & [(&str, &[u8])],
+) -> Vec<u8> {
+   use std::io::Write;
+
+   let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+   for (name, data) in members {
+      writer.start_file(*name, zip::write::FileOptions::default()).unwrap();
+      writer.write_all(data).unwrap();
+   }
+
+   return writer.finish().unwrap().into_inner();
+}
+
+#[test]
+fn read_members() {
+   use std::{
+      io::Write,
+      path::{Path, PathBuf},
+   };
+   use crate::archive::{read_members, ArchiveData};
+
+   let tar = create_tar(&[
+      ("src/game/mario.c",    b"June 23, 1995"),
+      ("src/game/camera.c",   b"July 4, 1995"),
+   ]);
+   let mut tar_gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+   tar_gz.write_all(&tar).unwrap();
+   let tar_gz = tar_gz.finish().unwrap();
+   let mut text_gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+   text_gz.write_all(b"March 3, 1997").unwrap();
+   let text_gz = text_gz.finish().unwrap();
+   let zip = create_zip(&[
+      ("readme.txt",          b"May 1, 1996"),
+      ("inner.tar.gz",        &tar_gz),
+      ("notes.txt.gz",        &text_gz),
+   ]);
+
+   let mut m0 = Vec::new();
+   let mut m1 = Vec::new();
+   let mut m2 = Vec::new();
+
+   let r0 = read_members(Path::new("dump.zip"), &zip, 1 << 20, & mut |p, d| m0.push((p, d))).unwrap();
+   let r1 = read_members(Path::new("dump.tar"), &tar, 1 << 20, & mut |p, d| m1.push((p, d))).unwrap();
+   let r2 = read_members(Path::new("text.txt"), b"June 23, 1995", 1 << 20, & mut |p, d| m2.push((p, d))).unwrap();
+   let r3 = read_members(Path::new("dump.zip"), &zip, 8, & mut |_, _| panic!());
+   let r4 = read_members(Path::new("text.gz"), &text_gz, 1 << 20, & mut |_, _| panic!()).unwrap();
+
+   assert!(r0 == ArchiveData::Archive);
+   assert!(r1 == ArchiveData::Archive);
+   assert!(r2 == ArchiveData::Plain(None));
+   assert!(r3.unwrap() == ArchiveData::Archive);
+   assert!(r4 == ArchiveData::Plain(Some(b"March 3, 1997".to_vec())));
+
+   assert!(m0 == vec![
+      (PathBuf::from("dump.zip!/readme.txt"),                        b"May 1, 1996".to_vec()),
+      (PathBuf::from("dump.zip!/inner.tar.gz!/src/game/mario.c"),    b"June 23, 1995".to_vec()),
+      (PathBuf::from("dump.zip!/inner.tar.gz!/src/game/camera.c"),   b"July 4, 1995".to_vec()),
+      (PathBuf::from("dump.zip!/notes.txt.gz"),                      b"March 3, 1997".to_vec()),
+   ]);
+   assert!(m1.len() == 2);
+   assert!(m2.is_empty());
+
+   return;
+}
+
+#[test]
+fn read_members_skips_failing_members() {
+   use std::{
+      io::Write,
+      path::{Path, PathBuf},
+   };
+   use crate::archive::{read_members, ArchiveData};
+
+   let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+   gzip.write_all(&create_tar(&[("lost.c", b"June 23, 1995")])).unwrap();
+   let gzip = gzip.finish().unwrap();
+   let truncated = &gzip[..gzip.len() / 2];
+
+   let zip = create_zip(&[
+      ("a.txt",            b"May 1, 1996"),
+      ("b.txt",            &[b'b'; 1024]),
+      ("inner.tar.gz",     truncated),
+      ("c.txt",            b"May 2, 1996"),
+   ]);
+   let tar = create_tar(&[
+      ("a.txt",            b"May 1, 1996"),
+      ("b.txt",            &[b'b'; 1024]),
+      ("c.txt",            b"May 2, 1996"),
+   ]);
+
+   let mut m0 = Vec::new();
+   let mut m1 = Vec::new();
+
+   let r0 = read_members(Path::new("dump.zip"), &zip, 256, & mut |p, d| m0.push((p, d))).unwrap();
+   let r1 = read_members(Path::new("dump.tar"), &tar, 256, & mut |p, d| m1.push((p, d))).unwrap();
+   let r2 = read_members(Path::new("dump.tar.gz"), truncated, 1 << 20, & mut |_, _| panic!());
+   let r3 = read_members(Path::new("dump.zip"), &zip[..zip.len() / 2], 1 << 20, & mut |_, _| panic!());
+
+   // Corrupt nested archives are passed as they are
+   assert!(r0 == ArchiveData::Archive);
+   assert!(m0 == vec![
+      (PathBuf::from("dump.zip!/a.txt"),           b"May 1, 1996".to_vec()),
+      (PathBuf::from("dump.zip!/inner.tar.gz"),    truncated.to_vec()),
+      (PathBuf::from("dump.zip!/c.txt"),           b"May 2, 1996".to_vec()),
+   ]);
+   assert!(r1 == ArchiveData::Archive);
+   assert!(m1 == vec![
+      (PathBuf::from("dump.tar!/a.txt"),           b"May 1, 1996".to_vec()),
+      (PathBuf::from("dump.tar!/c.txt"),           b"May 2, 1996".to_vec()),
+   ]);
+
+   // Archives which can't be opened at all
+   assert!(r2.is_err() == true);
+   assert!(r3.is_err() == true);
+
+   return;
+}
+
+#[test]
+fn read_members_nested_too_deeply() {
+   use std::path::Path;
+   use crate::archive::{read_members, ArchiveData, MAX_DEPTH};
+
+   // Wraps a file inside of the given amount of zip archives
+   let nest = |depth : usize| (0..depth).fold(b"June 23, 1995".to_vec(), |data, _| create_zip(&[
+      ("inner.zip",        &data),
+      ("readme.txt",       b"May 1, 1996"),
+   ]));
+
+   let mut m0 = Vec::new();
+   let mut m1 = Vec::new();
+
+   let r0 = read_members(Path::new("dump.zip"), &nest(MAX_DEPTH + 1), 1 << 20, & mut |p, _| m0.push(p)).unwrap();
+   let r1 = read_members(Path::new("dump.zip"), &nest(MAX_DEPTH + 2), 1 << 20, & mut |p, _| m1.push(p)).unwrap();
+
+   // Every archive is read until the deepest one is skipped
+   assert!(r0 == ArchiveData::Archive);
+   assert!(r1 == ArchiveData::Archive);
+   assert!(m0.len() == MAX_DEPTH + 2);
+   assert!(m1.len() == MAX_DEPTH + 1);
+   assert!(m0.iter().any(|p| p.ends_with("inner.zip")) == true );
+   assert!(m1.iter().any(|p| p.ends_with("inner.zip")) == false);
+
+   return;
+}
+
+#[test]
+fn may_be_archive() {
+   use crate::archive::may_be_archive;
+
+   assert!(may_be_archive(b"PK\x03\x04rest")         == true );
+   assert!(may_be_archive(b"\x1F\x8B\x08")           == true );
+   assert!(may_be_archive(b"BZh91AY&SY")             == true );
+   assert!(may_be_archive(&create_tar(&[("a", b"")])) == true );
+   assert!(may_be_archive(b"June 23, 1995")          == false);
+   assert!(may_be_archive(b"")                       == false);
+
+   return;
+}
@@ -0,0 +1,27 @@
+//! Unit tests for crate::category::DateCategory.
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::category::DateCategory::*;
+
+   assert!(Created  .to_string() == "created"  );
+   assert!(Modified .to_string() == "modified" );
+   assert!(Copyright.to_string() == "copyright");
+   assert!(Other    .to_string() == "other"    );
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::category::DateCategory;
+
+   for category in DateCategory::ALL {
+      assert!(category.name().parse::<DateCategory>().unwrap() == category);
+   }
+
+   assert!("MODIFIED".parse::<DateCategory>().unwrap() == DateCategory::Modified);
+   assert!("accessed".parse::<DateCategory>().is_err() == true);
+
+   return;
+}
@@ -0,0 +1,47 @@
+//! Unit tests for crate::category::KeywordTable.
+
+#[test]
+fn methods() {
+   use crate::category::{
+      DateCategory::*,
+      KeywordTable,
+   };
+
+   let mut table = KeywordTable::new();
+   table.insert("Last Edit", Modified);
+
+   assert!(table.keywords().len() == 1);
+   assert!(table.keywords()[0]    == (String::from("last edit"), Modified));
+
+   assert!(KeywordTable::parse_entry("modified:last edit").unwrap() == (Modified, String::from("last edit")));
+   assert!(KeywordTable::parse_entry("Created:written")   .unwrap() == (Created,  String::from("written"  )));
+   assert!(KeywordTable::parse_entry("modified")          .is_err() == true);
+   assert!(KeywordTable::parse_entry("modified:")         .is_err() == true);
+   assert!(KeywordTable::parse_entry("accessed:read")     .is_err() == true);
+
+   return;
+}
+
+#[test]
+fn classify() {
+   use crate::category::{
+      DateCategory::*,
+      KeywordTable,
+   };
+
+   let table = KeywordTable::default();
+   let text  = "Created: June 1 1995, Last Update: June 3 1995\nCopyright (C) 1995\nJune 5 1995\n";
+
+   assert!(table.classify(text, 9 ) == Created  );
+   assert!(table.classify(text, 35) == Modified );
+   assert!(table.classify(text, 47) == Copyright);
+   assert!(table.classify(text, 61) == Copyright);
+   assert!(table.classify(text, 66) == Other    );
+
+   let mut table = KeywordTable::new();
+   table.insert("edit", Modified);
+
+   assert!(table.classify(text, 9 ) == Other    );
+
+   return;
+}
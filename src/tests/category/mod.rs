@@ -0,0 +1,3 @@
+//! Unit tests for crate::category.
+mod date_category;
+mod keyword_table;
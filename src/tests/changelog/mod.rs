@@ -0,0 +1,40 @@
+//! Unit tests for crate::changelog.
+
+#[test]
+fn find_entries() {
+   use crate::{
+      changelog::find_entries,
+      date::{Date, Month::*},
+      extract::{DateExtractor, Extract},
+   };
+
+   let text = concat!(
+      "/*\n",
+      " * Revision history:\n",
+      " * June 30, 1995 by Nishiwaki: added castle\n",
+      " * June 23, 1995 - tk: fixed camera\n",
+      " *                     and lakitu\n",
+      " * July 2, 1996 - tk: more camera */\n",
+      "int x; // June 1, 1995\n",
+      "// June 3, 1995\n",
+   );
+
+   let dates = DateExtractor::default().find_all(text).unwrap();
+   let e0    = find_entries(text, &dates);
+
+   assert!(e0.len() == 3);
+   assert!(e0[0].date()         == Date::new(30, June, 1995).unwrap());
+   assert!(e0[0].offset()       == 27);
+   assert!(e0[0].author()       == Some("Nishiwaki"));
+   assert!(e0[0].description()  == "added castle");
+   assert!(e0[1].author()       == Some("tk"));
+   assert!(e0[1].description()  == "fixed camera and lakitu");
+   assert!(e0[2].date()         == Date::new(2, July, 1996).unwrap());
+   assert!(e0[2].description()  == "more camera");
+   assert!(e0[0].to_string()    == "June 30th, 1995 (Nishiwaki): added castle");
+
+   // A single dated line isn't a block
+   assert!(find_entries("// June 3, 1995\n", &DateExtractor::default().find_all("// June 3, 1995\n").unwrap()).is_empty());
+
+   return;
+}
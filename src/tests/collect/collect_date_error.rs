@@ -9,6 +9,7 @@ fn trait_std_fmt_display() {
    assert!(GeneralIOError  .to_string() == "General I/O error"    );
    assert!(FileIsDirectory .to_string() == "File is a directory"  );
    assert!(InvalidData     .to_string() == "Invalid data"         );
+   assert!(DataTooLarge    .to_string() == "Decompressed data exceeds the size limit");
 
    return;
 }
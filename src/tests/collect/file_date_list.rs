@@ -35,6 +35,81 @@ fn methods() {
    return;
 }
 
+#[test]
+fn from_text() {
+   use std::path::PathBuf;
+   use crate::{
+      date::{
+         Date,
+         Month::*,
+      },
+      collect::FileDateList,
+   };
+
+   let regex = regex::Regex::new(
+      r"(?P<m>[[:alpha:]]+)\s*(?P<d>\d{1,2}),?\s*(?P<y>\d+)",
+   ).unwrap();
+
+   let f0 = FileDateList::from_reader_with(
+      PathBuf::from("<stdin>"),
+      "Created June 23, 1995\nEdited May 2 1996\n".as_bytes(),
+      &regex,
+   ).unwrap();
+   let f1 = FileDateList::from_bytes_with(
+      PathBuf::from("binary.bin"),
+      &[0x4A, 0x75, 0x6E, 0xFF, 0xFE],
+      &regex,
+   );
+
+   assert!(f0.path()    == std::path::Path::new("<stdin>"));
+   assert!(f0.dates()   .as_slice() == &[
+      Date::new(23, June, 1995).unwrap(),
+      Date::new(2,  May,  1996).unwrap(),
+   ]);
+   assert!(f1.is_err()  == true);
+
+   return;
+}
+
+#[test]
+fn from_binary() {
+   use std::path::PathBuf;
+   use crate::{
+      date::{
+         Date,
+         Month::*,
+      },
+      collect::{
+         FileDateList,
+         SearchOptions,
+      },
+   };
+
+   let regex = regex::Regex::new(
+      r"(?P<m>[[:alpha:]]+)\s*(?P<d>\d{1,2}),?\s*(?P<y>\d+)",
+   ).unwrap();
+   let options = SearchOptions{
+      binary_strings : true,
+      ..SearchOptions::default()
+   };
+
+   let data = b"\x7FELF\x00\x00\x01build Jun 23 1995\x00\xFF\xFE";
+
+   let f0 = FileDateList::from_bytes_with_options(
+      PathBuf::from("mario.o"), data, &regex, &options,
+   ).unwrap();
+   let f1 = FileDateList::from_bytes_with(
+      PathBuf::from("mario.o"), data, &regex,
+   );
+
+   assert!(f0.dates().len()         == 1);
+   assert!(f0.dates()[0]            == Date::new(23, June, 1995).unwrap());
+   assert!(f0.dates()[0].offset()   == Some(13));
+   assert!(f1.is_err()              == true);
+
+   return;
+}
+
 #[test]
 fn trait_std_cmp_ord() {
    use std::{
@@ -136,3 +211,104 @@ fn trait_std_cmp_ord() {
    return;
 }
 
+
+#[test]
+fn from_text_near_misses() {
+   use std::path::PathBuf;
+   use crate::{
+      date::ParseDateError::*,
+      collect::{
+         FileDateList,
+         SearchOptions,
+      },
+      extract::DateExtractor,
+   };
+
+   let text    = "Released February 30, 1995\nEdited June 23, 1995\n";
+   let options = SearchOptions{
+      near_misses : true,
+      ..SearchOptions::default()
+   };
+
+   let f0 = FileDateList::from_text_with(
+      PathBuf::from("notes.txt"), text, &DateExtractor::default(),
+   ).unwrap();
+   let f1 = FileDateList::from_text_with_options(
+      PathBuf::from("notes.txt"), text, &DateExtractor::default(), &options,
+   ).unwrap();
+
+   assert!(f0.dates()   .len() == 1);
+   assert!(f0.rejected().is_empty() == true);
+   assert!(f1.dates()   .len() == 1);
+   assert!(f1.rejected().len() == 1);
+   assert!(f1.rejected()[0].text()   == "February 30, 1995");
+   assert!(f1.rejected()[0].offset() == 9);
+   assert!(f1.rejected()[0].reason() == InvalidDayOfMonth);
+
+   return;
+}
+
+#[test]
+fn from_text_min_confidence() {
+   use std::path::PathBuf;
+   use crate::{
+      collect::{
+         FileDateList,
+         SearchOptions,
+      },
+      extract::DateExtractor,
+   };
+
+   let text    = "// Created June 23, 1995\nversion Mar 3 1\n";
+   let options = SearchOptions{
+      min_confidence : 0.5,
+      ..SearchOptions::default()
+   };
+
+   let f0 = FileDateList::from_text_with(
+      PathBuf::from("main.c"), text, &DateExtractor::default(),
+   ).unwrap();
+   let f1 = FileDateList::from_text_with_options(
+      PathBuf::from("main.c"), text, &DateExtractor::default(), &options,
+   ).unwrap();
+
+   assert!(f0.dates().len() == 2);
+   assert!(f1.dates().len() == 1);
+   assert!(f1.dates()[0].offset()     == Some(11));
+   assert!(f1.dates()[0].confidence() >  0.9);
+
+   return;
+}
+
+#[test]
+fn from_text_comments_only() {
+   use std::path::PathBuf;
+   use crate::{
+      collect::{
+         FileDateList,
+         SearchOptions,
+      },
+      date::{Date, Month::*},
+      extract::DateExtractor,
+   };
+
+   let text    = "puts(\"June 1, 1995\"); // Created June 23, 1995\n";
+   let options = SearchOptions{
+      comments_only : true,
+      ..SearchOptions::default()
+   };
+
+   let f0 = FileDateList::from_text_with_options(
+      PathBuf::from("main.c"), text, &DateExtractor::default(), &options,
+   ).unwrap();
+   let f1 = FileDateList::from_text_with_options(
+      PathBuf::from("notes.txt"), text, &DateExtractor::default(), &options,
+   ).unwrap();
+
+   assert!(f0.dates().len() == 1);
+   assert!(f0.dates()[0].date()   == Date::new(23, June, 1995).unwrap());
+   assert!(f0.dates()[0].offset() == Some(33));
+   assert!(f1.dates().len() == 2);
+
+   return;
+}
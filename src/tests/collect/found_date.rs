@@ -0,0 +1,62 @@
+//! Unit tests for crate::collect::FoundDate.
+
+#[test]
+fn methods() {
+   use crate::{
+      collect::FoundDate,
+      date::{
+         Date,
+         Month::*,
+      },
+   };
+
+   let d0 = Date::new(23, June, 1995).unwrap();
+   let f0 = FoundDate::new(d0, Some(12));
+   let f1 = FoundDate::new(d0, None);
+
+   assert!(f0.date()    == d0       );
+   assert!(f0.offset()  == Some(12) );
+   assert!(f1.offset()  .is_none()  );
+   assert!(f0           == d0       );
+   assert!(f0.to_string() == "June 23rd, 1995");
+   assert!(f0.range()   .is_none()  );
+   assert!(f0.is_fuzzy() == false   );
+   assert!(f0.with_fuzzy(true).is_fuzzy() == true);
+   assert!(f0.confidence() == 1.0      );
+   assert!(f0.with_confidence(0.25).confidence() == 0.25);
+   assert!(f0.with_confidence(1.5) .confidence() == 1.0 );
+   assert!(f0.author()  .is_none()  );
+   assert!(f0.with_author(Some(String::from("tk"))).author() == Some("tk"));
+
+   let d1 = Date::new(25, June, 1995).unwrap();
+   let f2 = f0.with_range(d0, d1).with_offset(Some(40));
+
+   assert!(f2.date()    == d0             );
+   assert!(f2.offset()  == Some(40)       );
+   assert!(f2.range()   == Some((d0, d1)) );
+
+   return;
+}
+
+#[test]
+fn trait_std_cmp_ord() {
+   use std::cmp::Ordering::*;
+   use crate::{
+      collect::FoundDate,
+      date::{
+         Date,
+         Month::*,
+      },
+   };
+
+   let f0 = FoundDate::new(Date::new(23, June, 1995).unwrap(), Some(12));
+   let f1 = FoundDate::new(Date::new(23, June, 1995).unwrap(), Some(40));
+   let f2 = FoundDate::new(Date::new(22, June, 1995).unwrap(), Some(80));
+   let f3 = FoundDate::new(Date::new(23, June, 1995).unwrap(), Some(12));
+
+   assert!(f0.cmp(&f1) == Less   );
+   assert!(f0.cmp(&f2) == Greater);
+   assert!(f0.cmp(&f3) == Equal  );
+
+   return;
+}
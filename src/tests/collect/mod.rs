@@ -2,4 +2,5 @@
 mod collect_date_error;
 mod date_list;
 mod file_date_list;
-
+mod found_date;
+mod read_path_list;
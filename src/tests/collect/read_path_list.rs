@@ -0,0 +1,27 @@
+//! Unit tests for crate::collect::read_path_list.
+
+#[test]
+fn read_path_list() {
+   use std::path::PathBuf;
+   use crate::collect::read_path_list;
+
+   let s0 = "src/main.rs\nsrc/lib.rs\r\n\nREADME.md\n";
+   let s1 = "src/main.rs\0my file\nwith newline.txt\0\0";
+   let s2 = "";
+
+   let r0 = vec![
+      PathBuf::from("src/main.rs"),
+      PathBuf::from("src/lib.rs"),
+      PathBuf::from("README.md"),
+   ];
+   let r1 = vec![
+      PathBuf::from("src/main.rs"),
+      PathBuf::from("my file\nwith newline.txt"),
+   ];
+
+   assert!(read_path_list(s0.as_bytes()).unwrap() == r0);
+   assert!(read_path_list(s1.as_bytes()).unwrap() == r1);
+   assert!(read_path_list(s2.as_bytes()).unwrap().is_empty());
+
+   return;
+}
@@ -0,0 +1,43 @@
+//! Unit tests for crate::comments.
+
+#[test]
+fn from_path() {
+   use std::path::Path;
+   use crate::comments::CommentSyntax::{self, *};
+
+   assert!(CommentSyntax::from_path(Path::new("src/game/mario.c"))   == Some(C)        );
+   assert!(CommentSyntax::from_path(Path::new("MAIN.CPP"))           == Some(C)        );
+   assert!(CommentSyntax::from_path(Path::new("src/main.rs"))        == Some(Rust)     );
+   assert!(CommentSyntax::from_path(Path::new("boot.asm"))           == Some(Assembly) );
+   assert!(CommentSyntax::from_path(Path::new("build.sh"))           == Some(Hash)     );
+   assert!(CommentSyntax::from_path(Path::new("Makefile"))           == Some(Hash)     );
+   assert!(CommentSyntax::from_path(Path::new("dump.zip!/init.lua")) == Some(Lua)      );
+   assert!(CommentSyntax::from_path(Path::new("notes.txt"))          .is_none()        );
+   assert!(CommentSyntax::from_path(Path::new("README"))             .is_none()        );
+
+   return;
+}
+
+#[test]
+fn comment_ranges() {
+   use crate::comments::CommentSyntax::*;
+
+   let c    = "int x = 0; // one\nchar *s = \"// no\"; /* two\n three */ y";
+   let asm  = "mov ax, 1 ; one\ndb \"; no\", 0";
+   let hash = "# one\necho $# \"# no\" # two";
+   let lua  = "x = '-- no' -- one\n--[[ two\n]] y";
+   let rust = "fn f(x: &'static str) {} // one\nlet c = '\\''; let s = r#\"/* \"no\" */\"#; /* two /* nested */ still */ 'a' \"// no\nstill no\"";
+
+   let texts = |text : &'static str, ranges : Vec<std::ops::Range<usize>>| {
+      ranges.into_iter().map(|r| &text[r]).collect::<Vec<_>>()
+   };
+
+   assert!(texts(c,    C       .comment_ranges(c   )) == ["// one", "/* two\n three */"]);
+   assert!(texts(asm,  Assembly.comment_ranges(asm )) == ["; one"]);
+   assert!(texts(hash, Hash    .comment_ranges(hash)) == ["# one", "# two"]);
+   assert!(texts(lua,  Lua     .comment_ranges(lua )) == ["-- one", "--[[ two\n]]"]);
+   assert!(texts(rust, Rust    .comment_ranges(rust)) == ["// one", "/* two /* nested */ still */"]);
+   assert!(C.comment_ranges("/* unterminated")[0] == (0..15));
+
+   return;
+}
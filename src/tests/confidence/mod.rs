@@ -0,0 +1,84 @@
+//! Unit tests for crate::confidence.
+
+#[test]
+fn score() {
+   use crate::{
+      confidence::score,
+      date::{Date, Month::*},
+   };
+
+   let d0 = Date::new(5, May,   2000).unwrap();
+   let d1 = Date::new(3, March, 1   ).unwrap();
+
+   let s0 = score("We met on May 5 2000.",    10..20, &d0, 0.7);
+   let s1 = score("# Created May 5 2000",     10..20, &d0, 0.7);
+   let s2 = score("version Mar 3 1",          8 ..15, &d1, 0.7);
+   let s3 = score("Fri May  5 14:02:11 2000", 0 ..24, &d0, 1.0);
+
+   assert!((s0 - 0.7 ).abs() < 1e-9);
+   assert!((s1 - 0.95).abs() < 1e-9);
+   assert!(s2 < 0.2);
+   assert!(s3 == 1.0);
+
+   // A leading "*" only counts inside of a block comment
+   let s4 = score("/*\n * May 5 2000\n */",   6 ..16, &d0, 0.7);
+   let s5 = score("* May 5 2000",             2 ..12, &d0, 0.7);
+   let s6 = score("% May 5 2000",             2 ..12, &d0, 0.7);
+   assert!((s4 - 0.8 ).abs() < 1e-9);
+   assert!((s5 - 0.7 ).abs() < 1e-9);
+   assert!((s6 - 0.7 ).abs() < 1e-9);
+
+   return;
+}
+
+#[test]
+fn year_plausibility() {
+   use crate::confidence::year_plausibility;
+
+   assert!(year_plausibility(1995) == 1.0);
+   assert!(year_plausibility(1776) >  year_plausibility(500));
+   assert!(year_plausibility(500)  >  year_plausibility(1));
+   assert!(year_plausibility(9999) <  year_plausibility(1776));
+   assert!(year_plausibility(1)    >  year_plausibility(0));
+   assert!(year_plausibility(1)    >  year_plausibility(-500));
+
+   return;
+}
+
+#[test]
+fn has_keyword() {
+   use crate::confidence::has_keyword;
+
+   assert!(has_keyword("Last Modified: ")   == true );
+   assert!(has_keyword(" * Date: ")         == true );
+   assert!(has_keyword("We met on ")        == false);
+   assert!(has_keyword("created long ago, in a galaxy far, far away, and then on ") == false);
+
+   return;
+}
+
+#[test]
+fn is_comment() {
+   use crate::confidence::is_comment;
+
+   assert!(is_comment("// ")                == true );
+   assert!(is_comment("   # ")              == true );
+   assert!(is_comment("int x = 0; /* ")     == true );
+   assert!(is_comment("-- ")                == true );
+   assert!(is_comment("We met on ")         == false);
+   assert!(is_comment(" * ")                == false);
+   assert!(is_comment("% ")                 == false);
+
+   return;
+}
+
+#[test]
+fn in_block_comment() {
+   use crate::confidence::in_block_comment;
+
+   assert!(in_block_comment("/*\n * Created ",          14) == true );
+   assert!(in_block_comment("/* a */\n * Created ",     19) == false);
+   assert!(in_block_comment(" * Created ",              11) == false);
+
+   return;
+}
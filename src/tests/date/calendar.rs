@@ -0,0 +1,56 @@
+//! Unit tests for crate::date::Calendar.
+
+#[test]
+fn methods() {
+   use crate::date::Calendar::*;
+
+   assert!(Gregorian    .has_year_zero() == false);
+   assert!(Julian       .has_year_zero() == false);
+   assert!(Astronomical .has_year_zero() == true );
+
+   assert!(Gregorian    .astronomical_year(1995)  == 1995);
+   assert!(Gregorian    .astronomical_year(-1)    == 0   );
+   assert!(Julian       .astronomical_year(-44)   == -43 );
+   assert!(Astronomical .astronomical_year(-43)   == -43 );
+   assert!(Gregorian    .from_astronomical_year(0)   == -1 );
+   assert!(Astronomical .from_astronomical_year(0)   == 0  );
+   assert!(Gregorian    .year_before_common_era(44)  == -44);
+   assert!(Astronomical .year_before_common_era(44)  == -43);
+   assert!(Astronomical .year_before_common_era(1)   == 0  );
+
+   assert!(Gregorian    .is_leap_year(2000)  == true );
+   assert!(Gregorian    .is_leap_year(1900)  == false);
+   assert!(Julian       .is_leap_year(1900)  == true );
+   assert!(Gregorian    .is_leap_year(-1)    == true );
+   assert!(Gregorian    .is_leap_year(-5)    == true );
+   assert!(Astronomical .is_leap_year(-1)    == false);
+   assert!(Astronomical .is_leap_year(0)     == true );
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::date::Calendar::*;
+
+   assert!(Gregorian    .to_string() == "gregorian"   );
+   assert!(Julian       .to_string() == "julian"      );
+   assert!(Astronomical .to_string() == "astronomical");
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::date::Calendar;
+
+   for calendar in Calendar::ALL {
+      assert!(calendar.name().parse::<Calendar>().unwrap() == calendar);
+   }
+
+   assert!("Julian"  .parse::<Calendar>().unwrap() == Calendar::Julian);
+   assert!("hebrew"  .parse::<Calendar>().is_err() == true);
+   assert!(Calendar::default() == Calendar::Gregorian);
+
+   return;
+}
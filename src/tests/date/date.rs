@@ -118,6 +118,209 @@ fn from_text_single() {
    return;
 }
 
+#[test]
+fn from_captures() {
+   use crate::date::{Date, Month::*, Time};
+
+   let rx = regex::Regex::new(
+      r"(?P<m>[[:alpha:]]+) (?P<d>\d+) (?P<y>\d+)(?: (?P<H>\d+)(?::(?P<M>\d+))?(?::(?P<S>\d+))?)?",
+   ).unwrap();
+
+   let d0 = Date::from_text_single_with("June 23 1995",           &rx).unwrap();
+   let d1 = Date::from_text_single_with("June 23 1995 14:02:11",  &rx).unwrap();
+   let d2 = Date::from_text_single_with("June 23 1995 14",        &rx).unwrap();
+   let d3 = Date::from_text_single_with("June 23 1995 25:00",     &rx);
+
+   assert!(d0.time() .is_none());
+   assert!(d1.time() == Some(Time::new(14, 2, 11).unwrap()));
+   assert!(d2.time() == Some(Time::new(14, 0, 0 ).unwrap()));
+   assert!(d3.is_err() == true);
+   assert!(d0 <  d1);
+   assert!(d1 == Date::new(23, June, 1995).unwrap().with_time(Time::new(14, 2, 11).unwrap()));
+   assert!(d1.to_string() == "June 23rd, 1995 14:02:11");
+
+   return;
+}
+
+#[test]
+fn from_captures_partial() {
+   use crate::date::{Date, Month::*, Precision};
+
+   let rx = regex::Regex::new(
+      r"(?:(?P<m>[[:alpha:]]+) )?(?:Q(?P<q>\d) )?(?:(?P<d>\d{1,2}) )?(?P<y>\d{4})",
+   ).unwrap();
+
+   let d0 = Date::from_text_single_with("June 1995",     &rx).unwrap();
+   let d1 = Date::from_text_single_with("Q3 1995",       &rx).unwrap();
+   let d2 = Date::from_text_single_with("1995",          &rx).unwrap();
+   let d3 = Date::from_text_single_with("June 23 1995",  &rx).unwrap();
+   let d4 = Date::from_text_single_with("Q5 1995",       &rx);
+   let d5 = Date::from_text_single_with("23 1995",       &rx);
+
+   assert!(d0 == Date::new_month(June, 1995));
+   assert!(d1 == Date::new_quarter(3, 1995).unwrap());
+   assert!(d2 == Date::new_year(1995));
+   assert!(d3.precision() == Precision::Day);
+   assert!(d4.is_err() == true);
+   assert!(d5.is_err() == true);
+
+   return;
+}
+
+#[test]
+fn partial() {
+   use crate::date::{Date, Month::*, Precision, Time};
+
+   let d0 = Date::new_year(1995);
+   let d1 = Date::new_quarter(3, 1995).unwrap();
+   let d2 = Date::new_month(June, 1995);
+   let d3 = Date::new(1, June, 1995).unwrap();
+   let d4 = Date::new_month(June, 1995).with_time(Time::new(14, 2, 11).unwrap());
+
+   assert!(d0.precision() == Precision::Year    && d0.month() == January && d0.day_of_month() == 1);
+   assert!(d1.precision() == Precision::Quarter && d1.month() == July    && d1.day_of_month() == 1);
+   assert!(d2.precision() == Precision::Month   && d2.month() == June    && d2.day_of_month() == 1);
+   assert!(d1.quarter()   == 3);
+   assert!(d2.quarter()   == 2);
+   assert!(d4.time()      .is_none());
+   assert!(Date::new_quarter(0, 1995).is_err() == true);
+   assert!(Date::new_quarter(5, 1995).is_err() == true);
+
+   // Partial dates sort at the start of their period, before full dates
+   assert!(d0 < d2);
+   assert!(d2 < d3);
+   assert!(d3 < d1);
+   assert!(d2 < Date::new(23, June, 1995).unwrap());
+   assert!(d0 > Date::new(31, December, 1994).unwrap());
+
+   assert!(d0.to_string()                    == "1995"      );
+   assert!(d1.to_string()                    == "Q3 1995"   );
+   assert!(d2.to_string()                    == "June 1995" );
+   assert!(d4.to_string()                    == "June 1995" );
+   assert!(Date::new_year(-33).to_string()   == "33 BCE"    );
+
+   return;
+}
+
+#[test]
+fn days_since_epoch() {
+   use crate::date::{Date, Month::*};
+
+   let d0 = Date::new(1,  January,  1970).unwrap();
+   let d1 = Date::new(23, June,     1995).unwrap();
+   let d2 = Date::new(29, February, 2000).unwrap();
+   let d3 = Date::new(31, December, -1  ).unwrap();
+
+   assert!(d0.days_since_epoch() == 0    );
+   assert!(d1.days_since_epoch() == 9304 );
+   for date in [d0, d1, d2, d3] {
+      assert!(Date::from_days_since_epoch(date.days_since_epoch()) == date);
+   }
+   assert!(Date::from_days_since_epoch(d2.days_since_epoch() + 1) == Date::new(1, March, 2000).unwrap());
+
+   return;
+}
+
+#[test]
+fn calendar() {
+   use crate::date::{Calendar::*, Date, Month::*};
+
+   let d0 = Date::new_in(5,  October,  1582, Julian      ).unwrap();
+   let d1 = Date::new   (15, October,  1582              ).unwrap();
+   let d2 = Date::new_in(29, February, 1500, Julian      ).unwrap();
+   let d3 = Date::new_in(1,  January,  0,    Astronomical).unwrap();
+   let d4 = Date::new   (1,  January,  -1                ).unwrap();
+
+   assert!(Date::new      (1,  January,  0               ).is_err() == true);
+   assert!(Date::new_in   (1,  January,  0,    Julian    ).is_err() == true);
+   assert!(Date::new      (29, February, 1500            ).is_err() == true);
+   assert!(d2.with_calendar(Gregorian).is_err() == true);
+   assert!(d0.calendar() == Julian);
+
+   // Conversion keeps the same day
+   assert!(d0.days_since_epoch()   == d1.days_since_epoch());
+   assert!(d0.to_calendar(Gregorian) == d1);
+   assert!(d1.to_calendar(Julian)    == d0);
+   assert!(d3.to_calendar(Gregorian) == d4);
+   assert!(d4.to_calendar(Astronomical) == d3);
+   assert!(Date::new_year(-44).to_calendar(Astronomical).year() == -43);
+   for days in [-800000, -719468, 0, 9304, 200000] {
+      assert!(Date::from_days_since_epoch_in(days, Julian).days_since_epoch() == days);
+   }
+
+   // Dates in different calendars are ordered by day
+   assert!(d0 <  Date::new(16, October, 1582).unwrap());
+   assert!(d0 >  Date::new(14, October, 1582).unwrap());
+   assert!(d2 <  Date::new_in(1, March, 1500, Julian).unwrap());
+   assert!(d2 >  Date::new(1,  March,   1500).unwrap());
+
+   assert!(d0.to_string() == "October 5th, 1582 (Julian)");
+   assert!(d3.to_string() == "January 1st, 0");
+   assert!(d4.to_string() == "January 1st, 1 BCE");
+   assert!(Date::new_in(1, January, -5, Astronomical).unwrap().to_string() == "January 1st, -5");
+
+   return;
+}
+
+#[test]
+fn from_captures_era() {
+   use crate::date::{Calendar, Date, Month::*, ParseOptions};
+
+   let rx = regex::Regex::new(
+      r"(?P<m>[[:alpha:]]+) (?P<d>\d+), (?P<y>[+-]?\d+)(?: (?P<e>[A-Z.]+))?",
+   ).unwrap();
+   let astronomical = ParseOptions{
+      calendar : Calendar::Astronomical,
+      ..ParseOptions::default()
+   };
+
+   let d0 = Date::from_text_single_with("March 15, 44 BC",     &rx).unwrap();
+   let d1 = Date::from_text_single_with("March 15, 44 B.C.E.", &rx).unwrap();
+   let d2 = Date::from_text_single_with("June 23, 1995 AD",    &rx).unwrap();
+   let d3 = Date::from_captures_with(&rx.captures("March 15, 44 BC").unwrap(), &astronomical).unwrap().0;
+
+   assert!(d0 == Date::new(15, March, -44 ).unwrap());
+   assert!(d1 == d0);
+   assert!(d2 == Date::new(23, June,  1995).unwrap());
+   assert!(d3.year() == -43 && d3.calendar() == Calendar::Astronomical);
+   assert!(d3.days_since_epoch() == d0.days_since_epoch());
+   assert!(Date::from_text_single_with("March 15, 0 BC",   &rx).is_err() == true);
+   assert!(Date::from_text_single_with("March 15, -44 BC", &rx).is_err() == true);
+   assert!(Date::from_text_single_with("March 15, 44 XY",  &rx).is_err() == true);
+   assert!(Date::from_text_single_with("March 15, 0",      &rx).is_err() == true);
+
+   return;
+}
+
+#[test]
+fn from_captures_zone() {
+   use crate::date::{Date, TimeZone};
+
+   let rx = regex::Regex::new(
+      r"(?P<m>[[:alpha:]]+) (?P<d>\d+) (?P<y>\d+) (?P<H>\d+):(?P<M>\d+)(?: (?P<z>\S+))?",
+   ).unwrap();
+
+   let d0 = Date::from_text_single_with("June 23 1995 14:00 JST",    &rx).unwrap();
+   let d1 = Date::from_text_single_with("June 23 1995 06:00 UTC",    &rx).unwrap();
+   let d2 = Date::from_text_single_with("June 23 1995 06:00 -05:00", &rx).unwrap();
+   let d3 = Date::from_text_single_with("June 23 1995 06:00",        &rx).unwrap();
+   let d4 = Date::from_text_single_with("June 23 1995 06:00 XYZ",    &rx).unwrap();
+
+   assert!(d0.zone() == Some("JST".parse::<TimeZone>().unwrap()));
+   assert!(d2.zone() == Some(TimeZone::new(-300).unwrap()));
+   assert!(d3.zone().is_none());
+   assert!(d4 == d3);
+   assert!(d0 <  d1);
+   assert!(d1 <  d2);
+   assert!(d1 != d3);
+   assert!(d3 <  d1);
+   assert!(d0.to_string() == "June 23rd, 1995 14:00:00 JST");
+   assert!(d2.to_string() == "June 23rd, 1995 06:00:00 -05:00");
+   assert!(d0.seconds_since_epoch() == d1.seconds_since_epoch() - 3600);
+
+   return;
+}
+
 #[test]
 fn from_text_multi_sorted() {
    use crate::date::{Date, Month::*};
@@ -141,6 +344,67 @@ fn from_text_multi_sorted() {
 
    return;
 }
+#[test]
+fn to_iso8601() {
+   use crate::date::{Date, Month::*, Time, TimeZone};
+
+   let t0 = Time::new(14, 2, 11).unwrap();
+   let z0 = TimeZone::new(540).unwrap();
+   let z1 = TimeZone::new(-330).unwrap();
+   let d0 = Date::new(23, June, 1995).unwrap();
+   let d1 = d0.with_time(t0);
+   let d2 = Date::new_quarter(3, 1995).unwrap();
+
+   assert!(d0.to_iso8601()                            == "1995-06-23"               );
+   assert!(d1.to_iso8601()                            == "1995-06-23T14:02:11"      );
+   assert!(d1.with_zone(z0).to_iso8601()              == "1995-06-23T14:02:11+09:00");
+   assert!(d1.with_zone(z1).to_iso8601()              == "1995-06-23T14:02:11-05:30");
+   assert!(Date::new_month(June, 1995).to_iso8601()   == "1995-06"                  );
+   assert!(d2.to_iso8601()                            == "1995-Q3"                  );
+   assert!(Date::new_year(1995).to_iso8601()          == "1995"                     );
+
+   // Years before 1 CE are astronomical
+   assert!(Date::new_year(-1).to_iso8601()            == "0000"                     );
+   assert!(Date::new_year(-33).to_iso8601()           == "-0032"                    );
+
+   // Dates serialize as ISO 8601 text
+   assert!(serde_json::to_string(&Date::new_year(1995)).unwrap() == "\"1995\"");
+
+   return;
+}
+
+#[test]
+fn from_iso8601() {
+   use crate::date::{Date, Month::*, Time, TimeZone};
+
+   let t0 = Time::new(14, 2, 11).unwrap();
+   let z0 = TimeZone::new(-330).unwrap();
+   let d0 = Date::new(23, June, 1995).unwrap();
+
+   assert!(Date::from_iso8601("1995-06-23")                 == Ok(d0)                                 );
+   assert!(Date::from_iso8601("1995-06-23T14:02:11")        == Ok(d0.with_time(t0))                   );
+   assert!(Date::from_iso8601("1995-06-23T14:02:11-05:30")  == Ok(d0.with_time(t0).with_zone(z0))     );
+   assert!(Date::from_iso8601("1995-06")                    == Ok(Date::new_month(June, 1995))        );
+   assert!(Date::from_iso8601("1995-Q3")                    == Date::new_quarter(3, 1995)             );
+   assert!(Date::from_iso8601("1995")                       == Ok(Date::new_year(1995))               );
+
+   // Years before 1 CE are astronomical
+   assert!(Date::from_iso8601("0000")                       == Ok(Date::new_year(-1))                 );
+   assert!(Date::from_iso8601("-0032")                      == Ok(Date::new_year(-33))                );
+
+   // Invalid dates
+   assert!(Date::from_iso8601("June 23, 1995").is_err());
+   assert!(Date::from_iso8601("1995-13").is_err());
+   assert!(Date::from_iso8601("1995-02-30").is_err());
+   assert!(Date::from_iso8601("1995-Q5").is_err());
+
+   // Dates deserialize from ISO 8601 text
+   assert!(serde_json::from_str::<Date>("\"1995-06-23\"").unwrap() == d0);
+   assert!(serde_json::from_str::<Date>("\"tomorrow\"").is_err());
+
+   return;
+}
+
 #[test]
 fn trait_std_cmp_partialeq() {
    use crate::date::{Date, Month::*};
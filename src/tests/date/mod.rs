@@ -1,4 +1,9 @@
 //! Unit tests for crate::date.
 mod month;
 mod date;
+mod time;
 
+mod time_zone;
+mod precision;
+mod calendar;
+mod weekday;
@@ -33,6 +33,25 @@ fn days() {
    return;
 }
 
+#[test]
+fn from_str_fuzzy() {
+   use crate::date::Month::{self, *};
+
+   assert!(Month::from_str_fuzzy("June",      0).unwrap() == June      );
+   assert!(Month::from_str_fuzzy("Sept.",     0).unwrap() == September );
+   assert!(Month::from_str_fuzzy("Feburary",  0).unwrap() == February  );
+   assert!(Month::from_str_fuzzy("Setember",  2).unwrap() == September );
+   assert!(Month::from_str_fuzzy("Decmber",   2).unwrap() == December  );
+   assert!(Month::from_str_fuzzy("Novembre",  2).unwrap() == November  );
+   assert!(Month::from_str_fuzzy("Agust.",    2).unwrap() == August    );
+   assert!(Month::from_str_fuzzy("Novembre",  0).is_err() == true);
+   assert!(Month::from_str_fuzzy("Mayor",     2).is_err() == true);
+   assert!(Month::from_str_fuzzy("Juny",      2).is_err() == true);
+   assert!(Month::from_str_fuzzy("Monday",    2).is_err() == true);
+
+   return;
+}
+
 #[test]
 fn trait_std_fmt_display() {
    use crate::date::Month::*;
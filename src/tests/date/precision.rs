@@ -0,0 +1,24 @@
+//! Unit tests for crate::date::Precision.
+
+#[test]
+fn trait_std_cmp_ord() {
+   use crate::date::Precision::*;
+
+   assert!(Year      < Quarter );
+   assert!(Quarter   < Month   );
+   assert!(Month     < Day     );
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::date::Precision::*;
+
+   assert!(Year   .to_string() == "year"    );
+   assert!(Quarter.to_string() == "quarter" );
+   assert!(Month  .to_string() == "month"   );
+   assert!(Day    .to_string() == "day"     );
+
+   return;
+}
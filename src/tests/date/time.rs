@@ -0,0 +1,46 @@
+//! Unit tests for crate::date::Time.
+
+#[test]
+fn methods() {
+   use crate::date::Time;
+
+   let t0 = Time::new(14, 2,  11).unwrap();
+   let t1 = Time::new(23, 59, 60).unwrap();
+
+   assert!(
+      t0.hour()   == 14 &&
+      t0.minute() == 2  &&
+      t0.second() == 11
+   );
+   assert!(
+      t1.hour()   == 23 &&
+      t1.minute() == 59 &&
+      t1.second() == 60
+   );
+   assert!(Time::new(24, 0,  0 ).is_err() == true);
+   assert!(Time::new(0,  60, 0 ).is_err() == true);
+   assert!(Time::new(0,  0,  61).is_err() == true);
+
+   return;
+}
+
+#[test]
+fn trait_std_cmp_partialord() {
+   use crate::date::Time;
+
+   assert!(Time::new(14, 2, 11).unwrap() <  Time::new(14, 2, 12).unwrap());
+   assert!(Time::new(14, 2, 11).unwrap() <  Time::new(15, 0, 0 ).unwrap());
+   assert!(Time::new(9,  59, 59).unwrap() < Time::new(10, 0, 0 ).unwrap());
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::date::Time;
+
+   assert!(Time::new(14, 2, 11).unwrap().to_string() == "14:02:11");
+   assert!(Time::new(0,  0, 0 ).unwrap().to_string() == "00:00:00");
+
+   return;
+}
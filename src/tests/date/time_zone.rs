@@ -0,0 +1,52 @@
+//! Unit tests for crate::date::TimeZone.
+
+#[test]
+fn methods() {
+   use crate::date::TimeZone;
+
+   let z0 = TimeZone::new(540).unwrap();
+   let z1 = TimeZone::new(-330).unwrap();
+
+   assert!(z0.offset()        == 540 );
+   assert!(z1.offset()        == -330);
+   assert!(z0.abbreviation()  .is_none());
+   assert!(TimeZone::new(1440 ).is_err() == true);
+   assert!(TimeZone::new(-1440).is_err() == true);
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::date::TimeZone;
+
+   assert!(TimeZone::new(540 ).unwrap().to_string()      == "+09:00");
+   assert!(TimeZone::new(-330).unwrap().to_string()      == "-05:30");
+   assert!(TimeZone::new(0   ).unwrap().to_string()      == "+00:00");
+   assert!("jst".parse::<TimeZone>().unwrap().to_string() == "JST"   );
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::date::TimeZone;
+
+   let z0 = "JST"   .parse::<TimeZone>().unwrap();
+   let z1 = "gmt"   .parse::<TimeZone>().unwrap();
+   let z2 = "+0900" .parse::<TimeZone>().unwrap();
+   let z3 = "-05:30".parse::<TimeZone>().unwrap();
+   let z4 = "+02"   .parse::<TimeZone>().unwrap();
+
+   assert!(z0.offset() == 540 && z0.abbreviation() == Some("JST"));
+   assert!(z1.offset() == 0   && z1.abbreviation() == Some("GMT"));
+   assert!(z2.offset() == 540 && z2.abbreviation().is_none());
+   assert!(z3.offset() == -330);
+   assert!(z4.offset() == 120 );
+   assert!("XYZ"   .parse::<TimeZone>().is_err() == true);
+   assert!("+2400" .parse::<TimeZone>().is_err() == true);
+   assert!("+09:75".parse::<TimeZone>().is_err() == true);
+   assert!(""      .parse::<TimeZone>().is_err() == true);
+
+   return;
+}
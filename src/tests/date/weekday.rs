@@ -0,0 +1,29 @@
+//! Unit tests for crate::date::Weekday.
+
+#[test]
+fn day_of_week() {
+   use crate::date::{Date, Month::*, Weekday::*};
+
+   assert!(Date::new(1,  January,  1970).unwrap().day_of_week() == Thursday );
+   assert!(Date::new(23, June,     1995).unwrap().day_of_week() == Friday   );
+   assert!(Date::new(29, February, 2000).unwrap().day_of_week() == Tuesday  );
+   assert!(Date::new(31, December, 1969).unwrap().day_of_week() == Wednesday);
+   assert!(Date::new(15, March,    -44 ).unwrap().day_of_week() == Friday   );
+   assert!(Date::new_year(1995)          .day_of_week()         == Sunday   );
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::date::Weekday::{self, *};
+
+   assert!("Monday" .parse::<Weekday>().unwrap() == Monday  );
+   assert!("fri"    .parse::<Weekday>().unwrap() == Friday  );
+   assert!("SUN."   .parse::<Weekday>().unwrap() == Sunday  );
+   assert!("Frid"   .parse::<Weekday>() .is_err() == true);
+   assert!(Wednesday.to_string()                  == "Wednesday");
+   assert!(Wednesday.abbreviation()               == "Wed");
+
+   return;
+}
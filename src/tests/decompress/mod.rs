@@ -0,0 +1,87 @@
+//! Unit tests for crate::decompress.
+
+#[test]
+fn decompress() {
+   use std::io::Write;
+   use crate::decompress::{decompress, Compression};
+
+   let text = b"Created June 23, 1995\nEdited July 4, 1995\n";
+
+   let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+   gzip.write_all(text).unwrap();
+   let gzip = gzip.finish().unwrap();
+
+   let mut bzip2 = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+   bzip2.write_all(text).unwrap();
+   let bzip2 = bzip2.finish().unwrap();
+
+   let mut xz = xz2::write::XzEncoder::new(Vec::new(), 1);
+   xz.write_all(text).unwrap();
+   let xz = xz.finish().unwrap();
+
+   assert!(Compression::detect(&gzip)  == Some(Compression::Gzip ));
+   assert!(Compression::detect(&bzip2) == Some(Compression::Bzip2));
+   assert!(Compression::detect(&xz)    == Some(Compression::Xz   ));
+   assert!(Compression::detect(text)   .is_none());
+
+   assert!(decompress(&gzip,  1024).unwrap().unwrap() == text);
+   assert!(decompress(&bzip2, 1024).unwrap().unwrap() == text);
+   assert!(decompress(&xz,    1024).unwrap().unwrap() == text);
+   assert!(decompress(text,   1024).unwrap().is_none());
+
+   assert!(decompress(&gzip,  16).is_err() == true);
+   assert!(decompress(&xz,    16).is_err() == true);
+
+   // Truncated streams are invalid data
+   for data in [&gzip, &bzip2, &xz] {
+      assert!(matches!(
+         decompress(&data[..data.len() / 2], 1024),
+         Err(crate::collect::CollectDateError::InvalidData),
+      ));
+   }
+
+   return;
+}
+
+#[test]
+fn decompress_file() {
+   use std::{
+      io::Write,
+      path::Path,
+   };
+   use crate::{
+      collect::{CollectDateError, FileDateList, SearchOptions},
+      decompress::{decompress_file, Compression},
+      extract::DateExtractor,
+   };
+
+   let text = b"BZh is a prefix. Created May 5, 1995";
+
+   let mut bzip2 = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::fast());
+   bzip2.write_all(text).unwrap();
+   let bzip2 = bzip2.finish().unwrap();
+
+   assert!(Compression::from_path(Path::new("notes.txt.GZ"))   == Some(Compression::Gzip ));
+   assert!(Compression::from_path(Path::new("dump.tbz2"))      == Some(Compression::Bzip2));
+   assert!(Compression::from_path(Path::new("dump.txz"))       == Some(Compression::Xz   ));
+   assert!(Compression::from_path(Path::new("notes.txt"))      .is_none());
+
+   // Text which only starts with the magic bytes is searched as it is
+   assert!(decompress_file(Path::new("notes.txt"),      &bzip2, 1024).unwrap().unwrap() == text);
+   assert!(decompress_file(Path::new("notes.txt"),      text,   1024).unwrap().is_none());
+   assert!(matches!(
+      decompress_file(Path::new("notes.txt.bz2"), text, 1024),
+      Err(CollectDateError::InvalidData),
+   ));
+
+   let options = SearchOptions{
+      decompress  : true,
+      ..SearchOptions::default()
+   };
+   let f0 = FileDateList::from_bytes_with_options(
+      "notes.txt".into(), text, &DateExtractor::default(), &options,
+   ).unwrap();
+   assert!(f0.dates().len() == 1);
+
+   return;
+}
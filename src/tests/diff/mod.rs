@@ -0,0 +1,62 @@
+//! Unit tests for crate::diff.
+
+#[test]
+fn scan_diff() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      date::{Date, Month::*},
+      diff::ScanDiff,
+      extract::DateExtractor,
+      report::OutputFormat,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let before = [
+      file("a.c", "June 23, 1993 and March 3, 1997"),
+      file("b.c", "June 23, 1995"),
+      file("c.c", "June 23, 1996"),
+   ].into_iter().collect::<FileAggregateDateList>();
+   let after = [
+      file("a.c", "March 4, 1997 and June 23, 1993"),
+      file("c.c", "June 23, 1996"),
+      file("d.c", "June 1, 1990"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   // Files matched by path
+   let d0 = ScanDiff::new(&before, &after);
+   assert!(d0.added()   == [std::path::PathBuf::from("d.c")]);
+   assert!(d0.removed() == [std::path::PathBuf::from("b.c")]);
+   assert!(d0.changed().len() == 1);
+   assert!(d0.changed()[0].path()      == std::path::Path::new("a.c"));
+   assert!(d0.changed()[0].added()     == [Date::new(4, March, 1997).unwrap()]);
+   assert!(d0.changed()[0].removed()   == [Date::new(3, March, 1997).unwrap()]);
+   assert!(d0.is_empty() == false);
+
+   // Shifts in the summary
+   assert!(d0.oldest().before() == Some((std::path::Path::new("a.c"), Date::new(23, June, 1993).unwrap())));
+   assert!(d0.oldest().after()  == Some((std::path::Path::new("d.c"), Date::new(1,  June, 1990).unwrap())));
+   assert!(d0.oldest().days()   == Some(-1118));
+   assert!(d0.newest().days()   == Some(0));
+
+   // Identical scans and empty scans
+   let d1 = ScanDiff::new(&before, &before);
+   let d2 = ScanDiff::new(&FileAggregateDateList::from_iter([]), &before);
+   assert!(d1.is_empty());
+   assert!(d2.added().len() == 3);
+   assert!(d2.oldest().before().is_none() && d2.oldest().days().is_none());
+
+   // Output formats
+   let text = d0.to_format(OutputFormat::Text).unwrap();
+   let json = serde_json::from_str::<serde_json::Value>(&d0.to_format(OutputFormat::Json).unwrap()).unwrap();
+   assert!(text.contains("Files changed: 1\n"));
+   assert!(text.contains("   Moved 1118 days earlier\n"));
+   assert!(text.contains("\na.c\n   + March 4th, 1997\n   - March 3rd, 1997\n"));
+   assert!(json["added"][0]               == "d.c"        );
+   assert!(json["changed"][0]["removed"][0] == "1997-03-03" );
+   assert!(json["oldest"]["days"]         == -1118        );
+   assert!(d0.to_format(OutputFormat::Html).is_err());
+
+   return;
+}
@@ -0,0 +1,73 @@
+//! Unit tests for crate::directory.
+
+#[test]
+fn from_files() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      date::{Date, Month::*},
+      directory::DirectoryStats,
+      extract::DateExtractor,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("game/src/engine/camera.c",    "June 23, 1995 and June 30, 1995"),
+      file("game/src/engine/gfx/dl.c",    "May 2, 1995"),
+      file("game/src/level/castle.c",     "July 4, 1996"),
+      file("game/src/main.c",             "August 1, 1996"),
+      file("game/README",                 "No dates here"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let d0 = DirectoryStats::from_files(&data, 1);
+   let d1 = DirectoryStats::from_files(&data, 2);
+
+   // The README has no dates, so game/src is the root
+   assert!(d0.len() == 3);
+   assert!(d0[0].path()       == std::path::Path::new("game/src"));
+   assert!(d0[0].depth()      == 0);
+   assert!(d0[0].file_count() == 4);
+   assert!(d0[0].date_count() == 5);
+   assert!(d0[0].earliest()   == Date::new(2,  May,    1995).unwrap());
+   assert!(d0[0].latest()     == Date::new(1,  August, 1996).unwrap());
+   assert!(d0[0].median()     == Date::new(30, June,   1995).unwrap());
+
+   // Deeper files are counted in their ancestor
+   assert!(d0[1].path()       == std::path::Path::new("game/src/engine"));
+   assert!(d0[1].file_count() == 2);
+   assert!(d0[1].earliest()   == Date::new(2,  May,    1995).unwrap());
+   assert!(d0[2].path()       == std::path::Path::new("game/src/level"));
+   assert!(d1.len()           == 4);
+   assert!(d1[2].path()       == std::path::Path::new("game/src/engine/gfx"));
+   assert!(d1[2].depth()      == 2);
+
+   assert!(DirectoryStats::from_files(&data, 0).len() == 1);
+   assert!(DirectoryStats::from_files(&[].into_iter().collect(), 2).is_empty());
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      directory::DirectoryStats,
+      extract::DateExtractor,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("engine/camera.c",  "June 23, 1995"),
+      file("main.c",           "July 4, 1996"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let d0 = DirectoryStats::from_files(&data, 1);
+
+   assert!(d0[0].to_string() == "./ (2 files, June 23rd, 1995 to July 4th, 1996, median July 4th, 1996)");
+   assert!(d0[1].to_string() == "   engine/ (1 file, June 23rd, 1995 to June 23rd, 1995, median June 23rd, 1995)");
+
+   return;
+}
@@ -0,0 +1,64 @@
+//! Unit tests for crate::extract::BuiltinFormat.
+
+#[test]
+fn regex() {
+   use crate::{
+      date::{Date, Month::*, Time, TimeZone},
+      extract::BuiltinFormat::*,
+   };
+
+   let t0 = Time::new(14, 2, 11).unwrap();
+   let t1 = Time::new(14, 2, 0 ).unwrap();
+   let z0 = TimeZone::new(540).unwrap();
+   let z1 = "EST".parse::<TimeZone>().unwrap();
+
+   assert!(Date::from_text_single_with("Jun 23 1995",                      &CDate   .regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("Jun  3 1995",                      &CDate   .regex()).unwrap() == Date::new(3,  June, 1995).unwrap());
+   assert!(Date::from_text_single_with("Fri Jun 23 14:02:11 1995",         &CTime   .regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t0));
+   assert!(Date::from_text_single_with("Fri Jun  3 14:02:11 1995",         &CTime   .regex()).unwrap() == Date::new(3,  June, 1995).unwrap().with_time(t0));
+   assert!(Date::from_text_single_with("Fri, 23 Jun 1995 14:02:11 +0900",  &Rfc2822 .regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t0).with_zone(z0));
+   assert!(Date::from_text_single_with("23 Jun 1995 14:02",                &Rfc2822 .regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t1));
+   assert!(Date::from_text_single_with("Fri Jun 23 14:02:11 1995 EST",     &CTime   .regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t0).with_zone(z1));
+   assert!(Date::from_text_single_with("June 23rd, 1995",                  &MonthDayYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("June 23rd, 1995 14:02 +09:00",     &MonthDayYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t1).with_zone(z0));
+   assert!(Date::from_text_single_with("June 23rd, 1995 14:02 Hello",      &MonthDayYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap().with_time(t1));
+   assert!(Date::from_text_single_with("23 June 1995",                     &DayMonthYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("the 23rd of June, 1995",           &DayMonthYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("3 jun. 1995 14:02",                &DayMonthYear.regex()).unwrap() == Date::new(3,  June, 1995).unwrap().with_time(t1));
+   assert!(Date::from_text_single_with("23rd of June, nineteen ninety-five", &DayMonthYear.regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("the twenty-third of June, nineteen ninety-five", &SpelledOut.regex()).unwrap() == Date::new(23, June, 1995).unwrap());
+   assert!(Date::from_text_single_with("The First day of May, in the year 1995", &SpelledOut.regex()).unwrap() == Date::new(1,  May,  1995).unwrap());
+   assert!(Date::from_text_single_with("thirtieth of April, two thousand and five", &SpelledOut.regex()).unwrap() == Date::new(30, April, 2005).unwrap());
+
+   assert!(Date::from_text_single_with("Updated June 1995.",               &MonthYear.regex()).unwrap() == Date::new_month(June, 1995));
+   assert!(Date::from_text_single_with("jun., 1995",                       &MonthYear.regex()).unwrap() == Date::new_month(June, 1995));
+   assert!(Date::from_text_single_with("Q3 1995",                          &Quarter .regex()).unwrap() == Date::new_quarter(3, 1995).unwrap());
+   assert!(Date::from_text_single_with("Q4/1995",                          &Quarter .regex()).unwrap() == Date::new_quarter(4, 1995).unwrap());
+   assert!(Date::from_text_single_with("(c) 1995",                         &Copyright.regex()).unwrap() == Date::new_year(1995));
+   assert!(Date::from_text_single_with("Copyright (C) 1995 Foo",           &Copyright.regex()).unwrap() == Date::new_year(1995));
+   assert!(Date::from_text_single_with("\u{A9}1995",                       &Copyright.regex()).unwrap() == Date::new_year(1995));
+
+   assert!(Date::from_text_single_with("June 1995",                        &MonthDayYear.regex()).is_err() == true);
+   assert!(Date::from_text_single_with("23 June 95",                       &DayMonthYear.regex()).is_err() == true);
+   assert!(Date::from_text_single_with("the thirty-second of June, 1995",  &SpelledOut.regex()).is_err() == true);
+   assert!(Date::from_text_single_with("the first of May, one of them",    &SpelledOut.regex()).is_err() == true);
+   assert!(Date::from_text_single_with("Q5 1995",                          &Quarter .regex()).is_err() == true);
+   assert!(Date::from_text_single_with("June 23 1995",                     &CDate   .regex()).is_err() == true);
+   assert!(Date::from_text_single_with("Jun 23 14:02:11 1995",             &CTime   .regex()).is_err() == true);
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::extract::BuiltinFormat;
+
+   for format in BuiltinFormat::ALL {
+      assert!(format.name().parse::<BuiltinFormat>().unwrap() == format);
+   }
+
+   assert!("CTIME"   .parse::<BuiltinFormat>().unwrap() == BuiltinFormat::CTime);
+   assert!("iso8601" .parse::<BuiltinFormat>().is_err() == true);
+
+   return;
+}
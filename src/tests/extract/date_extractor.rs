@@ -0,0 +1,164 @@
+//! Unit tests for crate::extract::DateExtractor.
+
+#[test]
+fn trait_extract() {
+   use crate::{
+      collect::FoundDate,
+      date::{Date, Month::*, Time, TimeZone},
+      extract::{DateExtractor, Extract},
+   };
+
+   let s0 = "Built Fri Jun 23 14:02:11 1995 by make\nDate: Fri, 23 Jun 1995 14:02:11 +0900\nDATE Jul  4 1995";
+
+   let t0 = Time::new(14, 2, 11).unwrap();
+   let z0 = TimeZone::new(540).unwrap();
+
+   let r0 = vec![
+      FoundDate::new(Date::new(23, June, 1995).unwrap().with_time(t0),                Some(6) ),
+      FoundDate::new(Date::new(23, June, 1995).unwrap().with_time(t0).with_zone(z0),  Some(45)),
+      FoundDate::new(Date::new(4,  July, 1995).unwrap(),                              Some(82)),
+   ];
+
+   // The default extractor should not find "June 23rd, 14" in the ctime date
+   assert!(DateExtractor::default().find_all(s0).unwrap() == r0);
+
+   // A single regular expression behaves the same as on its own
+   let rx = regex::Regex::new(r"(?P<m>[[:alpha:]]+)\s+(?P<d>\d{1,2})\s+(?P<y>\d+)").unwrap();
+   assert!(DateExtractor::from(rx.clone()).find_all(s0).unwrap() == rx.find_all(s0).unwrap());
+
+   // Missing capture groups are an error
+   let rx = regex::Regex::new(r"(?P<m>[[:alpha:]]+)\s+(?P<d>\d{1,2})").unwrap();
+   assert!(DateExtractor::from(rx).find_all(s0).is_err() == true);
+
+   return;
+}
+
+#[test]
+fn trait_extract_range() {
+   use crate::{
+      date::{Date, Month::*},
+      extract::{DateExtractor, Extract},
+   };
+
+   let s0 = "Held June 23-25, 1995, June 29 - July 1, 1995, June 3, 5 and 7, 1995, and June 9, 1995.";
+
+   let r0 = DateExtractor::default().find_all(s0).unwrap();
+   let d0 = |day, month| Date::new(day, month, 1995).unwrap();
+
+   let dates = r0.iter().map(|f| f.date()).collect::<Vec<_>>();
+   assert!(dates == vec![
+      d0(23, June), d0(24, June), d0(25, June),
+      d0(29, June), d0(30, June), d0(1,  July),
+      d0(3,  June), d0(5,  June), d0(7,  June),
+      d0(9,  June),
+   ]);
+
+   assert!(r0[0].offset() == Some(5) && r0[2].offset() == Some(5));
+   assert!(r0[0].range()  == Some((d0(23, June), d0(25, June))));
+   assert!(r0[5].range()  == Some((d0(29, June), d0(1,  July))));
+   assert!(r0[7].range()  == Some((d0(3,  June), d0(7,  June))));
+   assert!(r0[9].range()  .is_none());
+
+   // Backwards ranges aren't expanded
+   let r1 = DateExtractor::default().find_all("June 25-23, 1995").unwrap();
+   assert!(r1.iter().all(|f| f.range().is_none()));
+
+   // Ranges ending in an earlier month end in the next year
+   let r2 = DateExtractor::default().find_all("December 28 - January 3, 1996").unwrap();
+   assert!(r2.len()      == 7);
+   assert!(r2[0].range() == Some((Date::new(28, December, 1996).unwrap(), Date::new(3, January, 1997).unwrap())));
+
+   return;
+}
+
+#[test]
+fn trait_extract_fuzzy() {
+   use crate::{
+      date::{Date, Month::*},
+      extract::{DateExtractor, Extract},
+   };
+
+   let s0 = "Feburary 3, 1995 and Setember 9, 1995 and June 4, 1995";
+
+   let mut extractor = DateExtractor::default();
+   assert!(extractor.fuzzy_months().is_none());
+   assert!(extractor.find_all(s0).unwrap().len() == 1);
+
+   extractor.set_fuzzy_months(Some(2));
+   let r0 = extractor.find_all(s0).unwrap();
+
+   assert!(extractor.fuzzy_months() == Some(2));
+   assert!(r0.len() == 3);
+   assert!(r0[0] == Date::new(3, February,  1995).unwrap() && r0[0].is_fuzzy() == true );
+   assert!(r0[1] == Date::new(9, September, 1995).unwrap() && r0[1].is_fuzzy() == true );
+   assert!(r0[2] == Date::new(4, June,      1995).unwrap() && r0[2].is_fuzzy() == false);
+
+   // Both ends of a range are recovered
+   let r1 = extractor.find_all("Feburary 3 - Marhc 5, 1995").unwrap();
+   assert!(r1.len()      == 31);
+   assert!(r1[0].range() == Some((Date::new(3, February, 1995).unwrap(), Date::new(5, March, 1995).unwrap())));
+   assert!(r1.iter().all(|f| f.is_fuzzy() == true));
+
+   return;
+}
+
+#[test]
+fn trait_extract_rejected() {
+   use crate::{
+      date::ParseDateError::*,
+      extract::{DateExtractor, Extract},
+   };
+
+   let s0 = "February 30, 1995 and Smarch 3, 1995 and June 23, 1995 and Copyright 1995";
+
+   let (r0, r1) = DateExtractor::default().find_all_with_rejected(s0).unwrap();
+
+   assert!(r0.len() == 2);
+   assert!(r1.len() == 2);
+   assert!(r1[0].text() == "February 30, 1995" && r1[0].offset() == 0  && r1[0].reason() == InvalidDayOfMonth     );
+   assert!(r1[1].text() == "Smarch 3, 1995"    && r1[1].offset() == 22 && r1[1].reason() == InvalidMonthFormatting);
+
+   // The plain regular expression reports the same rejections
+   let rx = regex::Regex::new(r"(?P<m>[[:alpha:]]+) (?P<d>\d{1,2}), (?P<y>\d{4})").unwrap();
+   let (_, r2) = rx.find_all_with_rejected(s0).unwrap();
+   assert!(r2 == r1);
+
+   return;
+}
+
+#[test]
+fn trait_extract_author() {
+   use crate::extract::{DateExtractor, Extract};
+
+   let s0 = "June 23, 1995 - tk: fixed camera\nJune 30, 1995 by Nishiwaki.\nJuly 2, 1996 - fixed\n";
+   let s1 = "1995/06/23 by Nishiwaki";
+
+   let r0 = DateExtractor::default().find_all(s0).unwrap();
+
+   assert!(r0.len() == 3);
+   assert!(r0[0].author() == Some("tk")       );
+   assert!(r0[1].author() == Some("Nishiwaki"));
+   assert!(r0[2].author() .is_none()          );
+
+   let mut extractor = DateExtractor::default();
+   extractor.set_author_patterns(vec![regex::Regex::new(r"^\s*\((?P<a>\w+)\)").unwrap()]);
+   let r1 = extractor.find_all("June 23, 1995 (tk)").unwrap();
+
+   assert!(extractor.author_patterns().len() == 1);
+   assert!(r1[0].author() == Some("tk"));
+
+   // Custom patterns don't search past the date's line
+   extractor.set_author_patterns(vec![regex::Regex::new(r"\((?P<a>\w+)\)").unwrap()]);
+   let r3 = extractor.find_all("June 23, 1995 - fixed (tk)\nJune 30, 1995\n(Nishiwaki)").unwrap();
+
+   assert!(r3[0].author() == Some("tk"));
+   assert!(r3[1].author() .is_none()   );
+
+   // Authors can be captured by the date regex itself
+   let rx = regex::Regex::new(r"(?P<y>\d{4})/(?P<m>\d{2})/(?P<d>\d{2}) by (?P<a>\w+)").unwrap();
+   let r2 = rx.find_all(s1).unwrap();
+
+   assert!(r2[0].author() == Some("Nishiwaki"));
+
+   return;
+}
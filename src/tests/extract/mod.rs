@@ -0,0 +1,4 @@
+//! Unit tests for crate::extract.
+mod builtin_format;
+mod date_extractor;
+mod rejected_match;
@@ -0,0 +1,34 @@
+//! Unit tests for crate::extract::RejectedMatch.
+
+#[test]
+fn methods() {
+   use crate::{
+      date::ParseDateError::*,
+      extract::RejectedMatch,
+   };
+
+   let r0 = RejectedMatch::new(String::from("February 30, 1995"), 9, InvalidDayOfMonth);
+   let r1 = r0.with_offset(40);
+
+   assert!(r0.text()    == "February 30, 1995");
+   assert!(r0.offset()  == 9 );
+   assert!(r0.reason()  == InvalidDayOfMonth);
+   assert!(r1.offset()  == 40);
+   assert!(r1.text()    == r0.text());
+
+   return;
+}
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::{
+      date::ParseDateError::*,
+      extract::RejectedMatch,
+   };
+
+   let r0 = RejectedMatch::new(String::from("Smarch 3, 1995"), 0x20, InvalidMonthFormatting);
+
+   assert!(r0.to_string() == "\"Smarch 3, 1995\" (offset 0x20): Month is not valid");
+
+   return;
+}
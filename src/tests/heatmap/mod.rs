@@ -0,0 +1,59 @@
+//! Unit tests for crate::heatmap.
+
+#[test]
+fn parse_years() {
+   use crate::heatmap::Heatmap;
+
+   assert!(Heatmap::parse_years("1995")      .unwrap() == (1995..=1995));
+   assert!(Heatmap::parse_years("1995-1997") .unwrap() == (1995..=1997));
+   assert!(Heatmap::parse_years("1997-1995") .is_err() == true);
+   assert!(Heatmap::parse_years("1900-2000") .is_err() == true);
+   assert!(Heatmap::parse_years("0")         .is_err() == true);
+   assert!(Heatmap::parse_years("ninety")    .is_err() == true);
+
+   return;
+}
+
+#[test]
+fn from_files() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      date::{Date, Month::*},
+      extract::DateExtractor,
+      heatmap::Heatmap,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c", "June 23, 1995 and June 23, 1995 and June 30, 1995"),
+      file("b.c", "June 23, 1995 and July 1995 and June 23, 1996"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let h0 = Heatmap::from_files(&data, 1995..=1995);
+
+   // Partial dates and dates outside the years aren't counted
+   assert!(h0.count(Date::new(23, June, 1995).unwrap()) == 3);
+   assert!(h0.count(Date::new(30, June, 1995).unwrap()) == 1);
+   assert!(h0.count(Date::new(1,  July, 1995).unwrap()) == 0);
+   assert!(h0.count(Date::new(23, June, 1996).unwrap()) == 0);
+   assert!(h0.max_count()  == 3);
+   assert!(h0.level(0)     == 0);
+   assert!(h0.level(1)     == 2);
+   assert!(h0.level(3)     == 4);
+
+   colored::control::set_override(false);
+   let s0 = h0.to_string();
+   let lines = s0.lines().collect::<Vec<_>>();
+
+   // January 1st, 1995 was a Sunday, so it's alone in the first week
+   assert!(lines[0] == "1995");
+   assert!(lines[1].starts_with("    Jan       Feb"));
+   assert!(lines[2].starts_with("Mon   \u{00B7} "));
+   assert!(lines[8].starts_with("Sun \u{00B7} \u{00B7} "));
+   assert!(lines.iter().filter(|l| l.contains('\u{2588}')).count() == 2);
+   assert!(s0.ends_with("More (busiest day has 3 dates)\n"));
+
+   return;
+}
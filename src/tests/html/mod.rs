@@ -0,0 +1,38 @@
+//! Unit tests for crate::html.
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      html::HtmlReport,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("src/<main>.c",   "June 23, 1995 and June 23, 1997"),
+      file("src/engine.c",   "June 23, 1996"),
+      file("README",         "No dates here"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let h0 = HtmlReport::from(&data).to_string();
+
+   assert!(h0.starts_with("<!DOCTYPE html>"));
+   assert!(h0.contains("<p>3 dates found in 2 of 3 files.</p>"));
+
+   // Paths are escaped and spans are relative to every date
+   assert!(h0.contains("src/&lt;main&gt;.c") && h0.contains("src/<main>.c") == false);
+   assert!(h0.contains("left: 0.000%; width: 100.000%"));
+   assert!(h0.contains("title=\"1996: 1 date\""));
+   assert!(h0.contains("<td data-sort=\"731\">731</td>"));
+
+   // Nothing is loaded over the network
+   assert!(h0.contains("http") == false);
+
+   let h1 = HtmlReport::from(&[].into_iter().collect()).to_string();
+   assert!(h1.contains("<table>") == false && h1.ends_with("</html>\n"));
+
+   return;
+}
@@ -0,0 +1,36 @@
+//! Unit tests for crate::markdown.
+
+#[test]
+fn trait_std_fmt_display() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      markdown::{DETAILS_THRESHOLD, MarkdownReport},
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+   let long = (1..=DETAILS_THRESHOLD + 1).map(|d| format!("June {d}, 1996")).collect::<Vec<_>>().join("\n");
+
+   let data = [
+      file("src/a|b.c",    "Created June 23, 1995 by tk_2"),
+      file("src/long.c",   &long),
+      file("README",       "No dates here"),
+   ].into_iter().collect::<FileAggregateDateList>();
+   let report = data.create_report().unwrap().with_directory_depth(0);
+
+   let m0 = MarkdownReport::from(&report).to_string();
+
+   assert!(m0.starts_with("# Dacom Report\n\n## Summary\n\n| Statistic | Value |\n| --- | --- |\n"));
+   assert!(m0.contains("| Files searched | 3 |\n| Files with dates | 2 |\n| Dates found | 12 |\n"));
+   assert!(m0.contains("| Median file | `src/a\\|b.c` (June 23rd, 1995) |\n"));
+   assert!(m0.contains("| `src` | 2 | 12 | June 23rd, 1995 | June 11th, 1996 | June 6th, 1996 |\n"));
+
+   // Short lists are written directly, long lists are collapsed
+   assert!(m0.contains("\n`src/a\\|b.c` (1 date)\n\n- June 23rd, 1995 by tk\\_2 (created)\n"));
+   assert!(m0.contains("\n<details>\n<summary><code>src/long.c</code> (11 dates)</summary>\n\n- June 1st, 1996\n"));
+   assert!(m0.ends_with("- June 11th, 1996\n\n</details>\n"));
+   assert!(m0.contains("`README` (") == false);
+
+   return;
+}
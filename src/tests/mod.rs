@@ -1,6 +1,24 @@
 //! Complete list of unit tests.
 
 // Unit test modules
+mod archive;
+mod category;
+mod changelog;
 mod collect;
+mod comments;
+mod confidence;
 mod date;
+mod decompress;
+mod diff;
+mod directory;
+mod extract;
+mod heatmap;
+mod html;
+mod markdown;
+mod report;
+mod scan;
+mod sort;
+mod strings;
+mod svg;
+mod words;
 
@@ -0,0 +1,63 @@
+//! Unit tests for crate::report.
+
+#[test]
+fn file_aggregate_report_limits() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",    "June 23, 1993 and June 24, 1993 and June 25, 1993"),
+      file("b.c",    "June 23, 1994"),
+      file("c.c",    "June 23, 1995"),
+      file("d.c",    "June 23, 1996 and June 24, 1996"),
+      file("README", "No dates here"),
+   ].into_iter().collect::<FileAggregateDateList>();
+   let paths = |files : Vec<&FileDateList>| files.iter()
+      .map(|fd| fd.path().to_str().unwrap().to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+
+   // Every file is listed by default
+   let r0 = data.create_report().unwrap();
+   let s0 = r0.to_string();
+   assert!(paths(r0.listed_files()) == "README a.c b.c c.c d.c");
+   assert!(r0.top_files().is_none());
+   assert!(r0.listed_dates(&data[1]).len() == 3);
+   assert!(s0.contains("----------- Raw Data ------------"));
+
+   // The oldest and newest files with dates
+   let r1 = data.create_report().unwrap().with_top(1);
+   let s1 = r1.to_string();
+   let (oldest, newest) = r1.top_files().unwrap();
+   assert!(paths(oldest)           == "a.c");
+   assert!(paths(newest)           == "d.c");
+   assert!(paths(r1.listed_files()) == "a.c d.c");
+   assert!(s1.contains("--------- Oldest Files ----------\n\na.c\n"));
+   assert!(s1.contains("--------- Newest Files ----------\n\nd.c\n"));
+   assert!(s1.contains("Raw Data") == false);
+
+   let r2 = data.create_report().unwrap().with_top(3);
+   assert!(paths(r2.listed_files()) == "a.c b.c c.c d.c");
+
+   // Dates are limited everywhere they're listed
+   let r3 = data.create_report().unwrap().with_date_limit(1);
+   let s3 = r3.to_string();
+   assert!(r3.listed_dates(&data[1]).len() == 1);
+   assert!(s3.contains("a.c\n   June 23rd, 1993 (offset 0x0) (confidence 0.70)\n   ... and 2 more dates\n"));
+   assert!(s3.contains("d.c\n   June 23rd, 1996 (offset 0x0) (confidence 0.70)\n   ... and 1 more date\n"));
+
+   // Only the summary is written
+   let r4 = data.create_report().unwrap().with_summary_only(true);
+   let s4 = r4.to_string();
+   assert!(r4.listed_files().is_empty());
+   assert!(s4.starts_with("--------- Data Summary ----------"));
+   assert!(s4.contains("Raw Data") == false);
+   assert!(r4.to_json().contains("\"files\": []"));
+
+   return;
+}
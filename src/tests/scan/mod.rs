@@ -0,0 +1,68 @@
+//! Unit tests for crate::scan.
+
+#[test]
+fn to_json_from_json() {
+   use crate::{
+      category::DateCategory,
+      collect::{FileAggregateDateList, FileDateList},
+      date::Calendar,
+      extract::DateExtractor,
+      scan,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("src/a.c",   "Created June 23, 1993 by tk"),
+      file("src/b.c",   "Copyright June 23-25, 1994, modified Q3 1995"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   // Everything known about each date is kept
+   let loaded = scan::from_json(&scan::to_json(&data)).unwrap();
+   assert!(loaded.len() == data.len());
+   assert!(loaded[0].dates()[0].author()     == Some("tk"));
+   assert!(loaded[0].dates()[0].category()   == DateCategory::Created);
+   assert!(loaded[1].dates()[0].range().is_some());
+   for (a, b) in data.iter().zip(loaded.iter()) {
+      assert!(a.path()        == b.path()       );
+      assert!(a.dates().len() == b.dates().len());
+      for (a, b) in a.dates().iter().zip(b.dates().iter()) {
+         assert!(a.date()        == b.date()       );
+         assert!(a.offset()      == b.offset()     );
+         assert!(a.range()       == b.range()      );
+         assert!(a.is_fuzzy()    == b.is_fuzzy()   );
+         assert!(a.confidence()  == b.confidence() );
+         assert!(a.category()    == b.category()   );
+         assert!(a.author()      == b.author()     );
+         assert!(a.length()      == b.length()     );
+      }
+   }
+
+   // The calendar and time zone abbreviation are kept
+   let mut julian = DateExtractor::default();
+   julian.set_calendar(Calendar::Julian);
+   let data = [
+      file("src/c.c",   "Fri, 23 Jun 1995 14:02:11 JST"),
+      FileDateList::from_text_with("src/d.c".into(), "June 23-25, 1994", &julian).unwrap(),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let loaded = scan::from_json(&scan::to_json(&data)).unwrap();
+   for (a, b) in data.iter().zip(loaded.iter()) {
+      for (a, b) in a.dates().iter().zip(b.dates().iter()) {
+         assert!(a.date()  == b.date() );
+         assert!(a.range() == b.range());
+      }
+   }
+   let date = |path : &str| loaded.iter().find(|fd| fd.path() == std::path::Path::new(path)).unwrap().dates()[0].date();
+   assert!(date("src/c.c").zone().unwrap().abbreviation()  == Some("JST"));
+   assert!(date("src/d.c").calendar()                      == Calendar::Julian);
+
+   // Invalid scans
+   assert!(scan::from_json("").is_err());
+   assert!(scan::from_json("{\"files\": []}").is_err());
+   assert!(scan::from_json("{\"version\": 0, \"files\": []}").is_err());
+   assert!(scan::from_json("{\"version\": 1, \"files\": []}").unwrap().is_empty());
+
+   return;
+}
@@ -0,0 +1,109 @@
+//! Unit tests for crate::sort.
+
+#[test]
+fn sort_order() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      sort::SortOrder::*,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",    "June 23, 1995 and June 30, 1995 and June 23, 1999"),
+      file("b.c",    "June 23, 1994"),
+      file("c.c",    "June 23, 1996 and June 23, 1997"),
+      file("d.c",    "June 23, 1996 and June 23, 1997"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let paths = |order, reverse| data.sorted_by(order, reverse, None).iter()
+      .map(|fd| fd.path().to_str().unwrap().to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+
+   assert!(paths(Oldest,      false) == "b.c a.c c.c d.c");
+   assert!(paths(Oldest,      true ) == "d.c c.c a.c b.c");
+   assert!(paths(Newest,      false) == "b.c c.c d.c a.c");
+   assert!(paths(Path,        true ) == "d.c c.c b.c a.c");
+   assert!(paths(Count,       false) == "a.c c.c d.c b.c");
+   assert!(paths(Span,        false) == "a.c c.c d.c b.c");
+   assert!(paths(LatestFirst, false) == "a.c c.c d.c b.c");
+   assert!(paths(LatestFirst, true ) == "b.c d.c c.c a.c");
+
+   return;
+}
+
+#[test]
+fn sort_order_category() {
+   use crate::{
+      category::DateCategory,
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      sort::SortOrder::*,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",    "Created: June 23, 1999\nJune 23, 1990"),
+      file("b.c",    "Created: June 23, 1995\nJune 23, 1998"),
+      file("c.c",    "June 23, 1991"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let paths = |order, reverse, category| data.sorted_by(order, reverse, category).iter()
+      .map(|fd| fd.path().to_str().unwrap().to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+
+   let created = Some(DateCategory::Created);
+   assert!(paths(Oldest, false, None   ) == "a.c c.c b.c");
+   assert!(paths(Oldest, false, created) == "c.c b.c a.c");
+   assert!(paths(Newest, false, created) == "c.c b.c a.c");
+   assert!(paths(Count,  false, created) == "a.c b.c c.c");
+
+   return;
+}
+
+#[test]
+fn sort_order_distant_years() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      sort::SortOrder::*,
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   // Years this far away don't fit in seconds since the epoch
+   let data = [
+      file("a.c",    "Created May 5 300000000000"),
+      file("b.c",    "Created May 5 1995"),
+      file("c.c",    "Created May 5 200000000000"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let paths = |order, reverse| data.sorted_by(order, reverse, None).iter()
+      .map(|fd| fd.path().to_str().unwrap().to_string())
+      .collect::<Vec<_>>()
+      .join(" ");
+
+   assert!(paths(Oldest,      false) == "b.c c.c a.c");
+   assert!(paths(LatestFirst, false) == "a.c c.c b.c");
+
+   return;
+}
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::sort::SortOrder;
+
+   assert!("oldest"       .parse::<SortOrder>().unwrap() == SortOrder::Oldest      );
+   assert!("Latest-First" .parse::<SortOrder>().unwrap() == SortOrder::LatestFirst );
+   assert!("size"         .parse::<SortOrder>() .is_err() == true);
+   assert!(SortOrder::default()                          == SortOrder::Oldest      );
+
+   return;
+}
@@ -0,0 +1,31 @@
+//! Unit tests for crate::strings.
+
+#[test]
+fn extract() {
+   use crate::strings::{extract, StringEncoding::*};
+
+   let mut data = Vec::new();
+   data.extend_from_slice(b"\x00\x01\x7FJun 23 1995\x00\xFF");
+   data.extend_from_slice(b"\x01\x02\x03");
+   data.extend("Jul 4 1995".encode_utf16().flat_map(|c| c.to_le_bytes()));
+   data.extend_from_slice(b"\x00\x00\xFE");
+
+   let s0 = extract(&data, 4);
+   let s1 = extract(&data, 11);
+
+   assert!(s0.len() == 2);
+   assert!(s0[0].offset()     == 3        );
+   assert!(s0[0].encoding()   == Ascii    );
+   assert!(s0[0].text()       == "Jun 23 1995");
+   assert!(s0[1].offset()     == 19       );
+   assert!(s0[1].encoding()   == Utf16Le  );
+   assert!(s0[1].text()       == "Jul 4 1995");
+   assert!(s0[1].data_offset(4) == 27     );
+
+   assert!(s1.len() == 1);
+   assert!(s1[0].text()       == "Jun 23 1995");
+
+   assert!(extract(b"", 4).is_empty());
+
+   return;
+}
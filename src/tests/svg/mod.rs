@@ -0,0 +1,116 @@
+//! Unit tests for crate::svg.
+
+#[test]
+fn trait_std_str_fromstr() {
+   use crate::svg::SvgChart;
+
+   assert!("histogram" .parse::<SvgChart>().unwrap() == SvgChart::Histogram);
+   assert!("GANTT"     .parse::<SvgChart>().unwrap() == SvgChart::Gantt    );
+   assert!("pie"       .parse::<SvgChart>() .is_err() == true);
+
+   return;
+}
+
+#[test]
+fn svg_report_gantt() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      sort::SortOrder,
+      svg::{SvgOptions, SvgReport},
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",       "June 23, 1996"),
+      file("b<1>.c",    "June 23, 1995 and June 23, 1997"),
+      file("README",    "No dates here"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let mut options = SvgOptions{
+      width    : 500,
+      height   : 100,
+      ..SvgOptions::default()
+   };
+
+   let s0 = SvgReport::new(&data, options).to_string();
+   options.order = SortOrder::Path;
+   let s1 = SvgReport::new(&data, options).to_string();
+
+   assert!(s0.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"500\" height=\"100\""));
+   assert!(s0.ends_with("</svg>\n"));
+   assert!(s0.matches("<rect ").count() == 3);
+
+   // Files are escaped and sorted by their first date
+   assert!(s0.find("b&lt;1&gt;.c").unwrap() < s0.find(">a.c<").unwrap());
+   assert!(s1.find("b&lt;1&gt;.c").unwrap() > s1.find(">a.c<").unwrap());
+   assert!(s0.contains("<title>b&lt;1&gt;.c: June 23rd, 1995 to June 23rd, 1997</title>"));
+
+   return;
+}
+
+#[test]
+fn svg_report_histogram() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      svg::{SvgChart, SvgOptions, SvgReport},
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",       "June 23, 1995 and June 30, 1995"),
+      file("b.c",       "August 1995 and Copyright 1990"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   let options = SvgOptions{
+      chart    : SvgChart::Histogram,
+      ..SvgOptions::default()
+   };
+
+   let s0 = SvgReport::new(&data, options).to_string();
+
+   // Dates without a month aren't counted
+   assert!(s0.contains("<title>June 1995: 2 dates</title>"));
+   assert!(s0.contains("<title>August 1995: 1 date</title>"));
+   assert!(s0.contains("July 1995") == false);
+   assert!(s0.contains("1990") == false);
+
+   let s1 = SvgReport::new(&[].into_iter().collect(), options).to_string();
+   assert!(s1.matches("<rect ").count() == 1);
+
+   return;
+}
+
+#[test]
+fn svg_report_distant_years() {
+   use crate::{
+      collect::{FileAggregateDateList, FileDateList},
+      extract::DateExtractor,
+      svg::{SvgChart, SvgOptions, SvgReport},
+   };
+
+   let extractor = DateExtractor::default();
+   let file = |path : &str, text : &str| FileDateList::from_text_with(path.into(), text, &extractor).unwrap();
+
+   let data = [
+      file("a.c",       "Created May 5 300000000000"),
+      file("b.c",       "Created May 5 1995"),
+   ].into_iter().collect::<FileAggregateDateList>();
+
+   // Only a few years are labelled, however long the span
+   for chart in [SvgChart::Gantt, SvgChart::Histogram] {
+      let options = SvgOptions{
+         chart    : chart,
+         ..SvgOptions::default()
+      };
+      let s0 = SvgReport::new(&data, options).to_string();
+      assert!(s0.matches("<text ").count() < 20);
+   }
+
+   return;
+}
@@ -0,0 +1,40 @@
+//! Unit tests for crate::words.
+
+#[test]
+fn parse_number() {
+   use crate::words::parse_number;
+
+   assert!(parse_number("first")                                  == Some(1)    );
+   assert!(parse_number("Twenty-Third")                           == Some(23)   );
+   assert!(parse_number("thirty one")                             == Some(31)   );
+   assert!(parse_number("twelfth")                                == Some(12)   );
+   assert!(parse_number("one thousand nine hundred and ninety-five") == Some(1995));
+
+   assert!(parse_number("")                                       .is_none()    );
+   assert!(parse_number("first twenty")                           .is_none()    );
+   assert!(parse_number("five six")                               .is_none()    );
+   assert!(parse_number("twenty nineteen")                        .is_none()    );
+   assert!(parse_number("twenty-third of")                        .is_none()    );
+
+   return;
+}
+
+#[test]
+fn parse_year() {
+   use crate::words::parse_year;
+
+   assert!(parse_year("nineteen ninety-five")        == Some(1995) );
+   assert!(parse_year("Nineteen-Ninety-Five")        == Some(1995) );
+   assert!(parse_year("twenty twenty-three")         == Some(2023) );
+   assert!(parse_year("nineteen oh five")            == Some(1905) );
+   assert!(parse_year("nineteen hundred")            == Some(1900) );
+   assert!(parse_year("nineteen hundred and five")   == Some(1905) );
+   assert!(parse_year("two thousand and five")       == Some(2005) );
+   assert!(parse_year("seventeen seventy-six")       == Some(1776) );
+
+   assert!(parse_year("nineteen oh")                 .is_none()    );
+   assert!(parse_year("nineteen five")               .is_none()    );
+   assert!(parse_year("june")                        .is_none()    );
+
+   return;
+}
@@ -0,0 +1,168 @@
+//! Utilities for parsing numbers written out
+//! as English words.
+//!
+//! Formal documents often spell out dates, such
+//! as "the twenty-third of June, nineteen
+//! ninety-five".  Both cardinal ("twenty-three")
+//! and ordinal ("twenty-third") numbers are
+//! understood, along with years spoken in pairs
+//! of digits ("nineteen oh five").  Words are
+//! matched case-insensitively and may be
+//! separated by spaces, hyphens, or "and".
+
+///////////////
+// Constants //
+///////////////
+
+/// Regular expression alternation matching
+/// every ordinal word for a day of the month,
+/// excluding the tens prefix of compound
+/// ordinals such as "twenty-third".
+pub const RX_ORDINAL_WORD : &str = "first|second|third|fourth|fifth|sixth|seventh|eighth|ninth|tenth|eleventh|twelfth|thirteenth|fourteenth|fifteenth|sixteenth|seventeenth|eighteenth|nineteenth|twentieth|thirtieth";
+
+/// Regular expression alternation matching
+/// every cardinal word used to spell out a
+/// year, with longer words listed first.
+pub const RX_CARDINAL_WORD : &str = "thirteen|fourteen|fifteen|sixteen|seventeen|eighteen|nineteen|twenty|thirty|forty|fifty|sixty|seventy|eighty|ninety|eleven|twelve|thousand|hundred|one|two|three|four|five|six|seven|eight|nine|ten|oh";
+
+const CARDINAL_WORDS : [(&str, usize); 27] = [
+   ("one",        1), ("two",        2), ("three",      3),
+   ("four",       4), ("five",       5), ("six",        6),
+   ("seven",      7), ("eight",      8), ("nine",       9),
+   ("ten",       10), ("eleven",    11), ("twelve",    12),
+   ("thirteen",  13), ("fourteen",  14), ("fifteen",   15),
+   ("sixteen",   16), ("seventeen", 17), ("eighteen",  18),
+   ("nineteen",  19), ("twenty",    20), ("thirty",    30),
+   ("forty",     40), ("fifty",     50), ("sixty",     60),
+   ("seventy",   70), ("eighty",    80), ("ninety",    90),
+];
+
+const ORDINAL_WORDS : [(&str, usize); 27] = [
+   ("first",         1), ("second",        2), ("third",         3),
+   ("fourth",        4), ("fifth",         5), ("sixth",         6),
+   ("seventh",       7), ("eighth",        8), ("ninth",         9),
+   ("tenth",        10), ("eleventh",     11), ("twelfth",      12),
+   ("thirteenth",   13), ("fourteenth",   14), ("fifteenth",    15),
+   ("sixteenth",    16), ("seventeenth",  17), ("eighteenth",   18),
+   ("nineteenth",   19), ("twentieth",    20), ("thirtieth",    30),
+   ("fortieth",     40), ("fiftieth",     50), ("sixtieth",     60),
+   ("seventieth",   70), ("eightieth",    80), ("ninetieth",    90),
+];
+
+///////////////
+// Functions //
+///////////////
+
+/// Parses a cardinal or ordinal number written
+/// in English words, such as "twenty-third" or
+/// "one thousand nine hundred and ninety-five".
+/// An ordinal word may only appear last.
+/// Returns None if the text isn't a number.
+pub fn parse_number(
+   text  : & str,
+) -> Option<usize> {
+   return internal_parse_words(&internal_split_words(text));
+}
+
+/// Parses a year written in English words.
+/// Years may be spoken as a pair of two-digit
+/// numbers, such as "nineteen ninety-five",
+/// "twenty twenty-three", or "nineteen oh five",
+/// or as a whole number, such as "two thousand
+/// and five" or "nineteen hundred".  Returns
+/// None if the text isn't a year.
+pub fn parse_year(
+   text  : & str,
+) -> Option<usize> {
+   let words = internal_split_words(text);
+
+   // Try every split of the words into a pair of numbers
+   if words.iter().all(|w| w != "hundred" && w != "thousand") {
+      for split in 1..words.len() {
+         let (century, rest) = words.split_at(split);
+
+         let century = match internal_parse_words(century) {
+            Some(c) if (10..100).contains(&c)   => c,
+            _                                   => continue,
+         };
+         let rest = match rest {
+            [oh, _] if oh == "oh"      => internal_parse_words(&rest[1..])
+               .filter(|u| (1..10).contains(u)),
+            _                          => internal_parse_words(rest)
+               .filter(|r| (10..100).contains(r)),
+         };
+
+         if let Some(rest) = rest {
+            return Some(century * 100 + rest);
+         }
+      }
+   }
+
+   return internal_parse_words(&words);
+}
+
+//////////////////////
+// Internal helpers //
+//////////////////////
+
+/// Splits text into lowercase words, ignoring
+/// hyphens and the word "and".
+fn internal_split_words(
+   text  : & str,
+) -> Vec<String> {
+   return text.split(|c : char| c.is_whitespace() || c == '-')
+      .filter(|w| w.is_empty() == false)
+      .map(|w| w.to_lowercase())
+      .filter(|w| w != "and")
+      .collect();
+}
+
+/// Parses a list of words as a whole number.
+/// Units must follow tens, and tens may only
+/// start a new group of hundreds.
+fn internal_parse_words(
+   words : & [String],
+) -> Option<usize> {
+   if words.is_empty() {
+      return None;
+   }
+
+   let mut total     = 0;
+   let mut current   = 0;
+   for (index, word) in words.iter().enumerate() {
+      let last = index + 1 == words.len();
+
+      match word.as_str() {
+         "hundred"   => {
+            if current >= 100 {
+               return None;
+            }
+            current = current.max(1) * 100;
+         },
+         "thousand"  => {
+            if total != 0 {
+               return None;
+            }
+            total = current.max(1) * 1000;
+            current = 0;
+         },
+         _           => {
+            let value = CARDINAL_WORDS.iter()
+               .chain(ORDINAL_WORDS.iter().filter(|_| last))
+               .find(|(name, _)| name == word)?.1;
+
+            // Reject sequences such as "five six" or "twenty nineteen"
+            if value < 10 && current % 10 != 0 {
+               return None;
+            }
+            if value >= 10 && current % 100 != 0 {
+               return None;
+            }
+            current += value;
+         },
+      }
+   }
+
+   return Some(total + current);
+}
+